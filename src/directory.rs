@@ -8,9 +8,26 @@
 mod directory_test;
 
 use crate::error::FsIOError;
+use crate::file::is_cross_device_error;
+#[cfg(feature = "hash")]
+use crate::file::{hash as hash_file, HashAlgorithm, Hasher};
 use crate::path::as_path::AsPath;
 use crate::path::get_parent_directory;
-use std::fs::{create_dir_all, remove_dir_all};
+#[cfg(feature = "hash")]
+use crate::path::normalize;
+use std::fs::{
+    copy as copy_file, create_dir_all, metadata, read_dir, read_link, remove_dir_all,
+    remove_file, rename, set_permissions, symlink_metadata, File, FileTimes, FileType, Metadata,
+};
+#[cfg(feature = "hash")]
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::io;
+#[cfg(feature = "hash")]
+use std::io::{BufWriter, Write};
+#[cfg(feature = "hash")]
+use std::path::Component;
+use std::path::{Path, PathBuf};
 
 /// Creates the directory (and if needed the parent directories) for the provided path.
 ///
@@ -123,3 +140,1447 @@ pub fn delete<T: AsPath + ?Sized>(path: &T) -> Result<(), FsIOError> {
         Ok(())
     }
 }
+
+/// Defines what to do when a copied file already exists in the target directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverwritePolicy {
+    /// Keep the existing file
+    Skip,
+    /// Replace the existing file
+    Overwrite,
+    /// Abort the copy with an error
+    Error,
+}
+
+/// Defines how symbolic links found in the source directory are copied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Create a symbolic link pointing to the same location
+    CopyLink,
+    /// Copy the content the symbolic link points to
+    Follow,
+}
+
+/// Holds the directory copy options.
+pub struct CopyOptions {
+    /// What to do when a file already exists in the target directory (default overwrite)
+    pub overwrite: OverwritePolicy,
+    /// How symbolic links are copied (default copy link)
+    pub symlinks: SymlinkPolicy,
+    /// True to copy the permissions of the files and directories (default true)
+    pub preserve_permissions: bool,
+    /// True to copy the last modified/accessed time of the files and directories (default false)
+    pub preserve_modified_time: bool,
+    /// Optional filter, only files for which it returns true are copied.
+    /// It is invoked with the path relative to the source directory and is not applied on
+    /// directories.
+    pub include: Option<Box<dyn Fn(&Path) -> bool>>,
+    /// Optional filter, files and directories for which it returns true are not copied.
+    /// It is invoked with the path relative to the source directory.
+    pub exclude: Option<Box<dyn Fn(&Path) -> bool>>,
+}
+
+impl CopyOptions {
+    /// Returns new instance with default values.
+    pub fn new() -> CopyOptions {
+        CopyOptions {
+            overwrite: OverwritePolicy::Overwrite,
+            symlinks: SymlinkPolicy::CopyLink,
+            preserve_permissions: true,
+            preserve_modified_time: false,
+            include: None,
+            exclude: None,
+        }
+    }
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        CopyOptions::new()
+    }
+}
+
+/// Recursively copies the source directory content (files, directories and symbolic links)
+/// into the target directory.
+/// The target directory (and if needed the parent directories) is created if missing.
+///
+/// # Arguments
+///
+/// * `source` - The source directory path
+/// * `target` - The target directory path
+/// * `options` - The copy options
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::{directory, file};
+/// use crate::fsio::directory::CopyOptions;
+/// use std::path::Path;
+///
+/// fn main() {
+///     file::write_text_file("./target/__test/directory_test/copy/source/dir1/file.txt", "text").unwrap();
+///     file::write_text_file("./target/__test/directory_test/copy/source/file.log", "log").unwrap();
+///
+///     let mut options = CopyOptions::new();
+///     options.exclude = Some(Box::new(|path: &Path| {
+///         path.extension().map_or(false, |extension| extension == "log")
+///     }));
+///     let result = directory::copy(
+///         "./target/__test/directory_test/copy/source",
+///         "./target/__test/directory_test/copy/target",
+///         &options,
+///     );
+///     assert!(result.is_ok());
+///
+///     assert!(Path::new("./target/__test/directory_test/copy/target/dir1/file.txt").exists());
+///     assert!(!Path::new("./target/__test/directory_test/copy/target/file.log").exists());
+/// }
+/// ```
+pub fn copy<S: AsPath + ?Sized, D: AsPath + ?Sized>(
+    source: &S,
+    target: &D,
+    options: &CopyOptions,
+) -> Result<(), FsIOError> {
+    let source_path = source.as_path();
+    let target_path = target.as_path();
+
+    if !source_path.is_dir() {
+        return Err(FsIOError::NotDirectory(
+                format!("Path: {:?} is not a directory.", &source_path).to_string(),
+            ),
+        );
+    }
+
+    create(target)?;
+
+    let mut ancestors = vec![canonicalize(source_path)?];
+    let target_canonical = canonicalize(target_path)?;
+
+    copy_directory_content(
+        source_path,
+        target_path,
+        Path::new(""),
+        options,
+        &target_canonical,
+        &mut ancestors,
+    )?;
+
+    match metadata(source_path) {
+        Ok(source_metadata) => copy_attributes(&source_metadata, target_path, options),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to read metadata for: {:?}", &source_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+fn canonicalize(path: &Path) -> Result<PathBuf, FsIOError> {
+    match path.canonicalize() {
+        Ok(value) => Ok(value),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to canonicalize path: {:?}", &path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+fn copy_directory_content(
+    source_directory: &Path,
+    target_directory: &Path,
+    relative_directory: &Path,
+    options: &CopyOptions,
+    target_canonical: &Path,
+    ancestors: &mut Vec<PathBuf>,
+) -> Result<(), FsIOError> {
+    let entries = match read_dir(source_directory) {
+        Ok(entries) => entries,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read directory: {:?}", &source_directory).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    for entry in entries {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read directory: {:?}", &source_directory).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        };
+
+        let source_path = entry.path();
+        let target_path = target_directory.join(entry.file_name());
+        let relative_path = relative_directory.join(entry.file_name());
+
+        if let Some(ref exclude) = options.exclude {
+            if exclude(&relative_path) {
+                continue;
+            }
+        }
+
+        let mut source_metadata = match symlink_metadata(&source_path) {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read metadata for: {:?}", &source_path).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        };
+
+        if source_metadata.file_type().is_symlink() {
+            match options.symlinks {
+                SymlinkPolicy::CopyLink => {
+                    if is_included(&relative_path, options) {
+                        copy_symlink(&source_path, &target_path, options)?;
+                    }
+                    continue;
+                }
+                SymlinkPolicy::Follow => {
+                    source_metadata = match metadata(&source_path) {
+                        Ok(value) => value,
+                        Err(error) => {
+                            return Err(FsIOError::IOError(
+                                    format!("Unable to follow symbolic link: {:?}", &source_path)
+                                        .to_string(),
+                                    Some(error),
+                                ),
+                            )
+                        }
+                    }
+                }
+            }
+        }
+
+        if source_metadata.is_dir() {
+            let source_canonical = canonicalize(&source_path)?;
+            if source_canonical == target_canonical {
+                continue;
+            }
+            if ancestors.contains(&source_canonical) {
+                return Err(FsIOError::IOError(
+                        format!("Directory cycle detected at: {:?}", &source_path).to_string(),
+                        None,
+                    ),
+                );
+            }
+
+            create(&target_path)?;
+
+            ancestors.push(source_canonical);
+            let result = copy_directory_content(
+                &source_path,
+                &target_path,
+                &relative_path,
+                options,
+                target_canonical,
+                ancestors,
+            );
+            ancestors.pop();
+            result?;
+
+            copy_attributes(&source_metadata, &target_path, options)?;
+        } else if is_included(&relative_path, options) {
+            if !prepare_target(&target_path, options)? {
+                continue;
+            }
+
+            copy_file_content(&source_path, &target_path, options)?;
+            copy_attributes(&source_metadata, &target_path, options)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn is_included(relative_path: &Path, options: &CopyOptions) -> bool {
+    match options.include {
+        Some(ref include) => include(relative_path),
+        None => true,
+    }
+}
+
+/// Returns true if the target path is free to be written, based on the overwrite policy.
+fn prepare_target(target_path: &Path, options: &CopyOptions) -> Result<bool, FsIOError> {
+    match symlink_metadata(target_path) {
+        Ok(target_metadata) => {
+            if target_metadata.is_dir() {
+                return Err(FsIOError::PathAlreadyExists(
+                        format!("Unable to copy to: {:?}, path is a directory.", &target_path)
+                            .to_string(),
+                    ),
+                );
+            }
+
+            match options.overwrite {
+                OverwritePolicy::Skip => Ok(false),
+                OverwritePolicy::Error => Err(FsIOError::PathAlreadyExists(
+                        format!("Unable to copy to: {:?}, path already exists.", &target_path)
+                            .to_string(),
+                    ),
+                ),
+                OverwritePolicy::Overwrite => match remove_file(target_path) {
+                    Ok(_) => Ok(true),
+                    Err(error) => Err(FsIOError::IOError(
+                            format!("Unable to delete file: {:?}", &target_path).to_string(),
+                            Some(error),
+                        ),
+                    ),
+                },
+            }
+        }
+        Err(_) => Ok(true),
+    }
+}
+
+fn copy_file_content(
+    source_path: &Path,
+    target_path: &Path,
+    options: &CopyOptions,
+) -> Result<(), FsIOError> {
+    // std copy also copies the permission bits
+    let result = if options.preserve_permissions {
+        copy_file(source_path, target_path).map(|_| ())
+    } else {
+        File::open(source_path).and_then(|mut source_fd| {
+            let mut target_fd = File::create(target_path)?;
+            io::copy(&mut source_fd, &mut target_fd).map(|_| ())
+        })
+    };
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to copy: {:?} to: {:?}", &source_path, &target_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+fn copy_symlink(
+    source_path: &Path,
+    target_path: &Path,
+    options: &CopyOptions,
+) -> Result<(), FsIOError> {
+    if !prepare_target(target_path, options)? {
+        return Ok(());
+    }
+
+    let link = match read_link(source_path) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read symbolic link: {:?}", &source_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    match create_symlink(source_path, &link, target_path) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to create symbolic link: {:?}", &target_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+#[cfg(windows)]
+pub(crate) fn create_symlink(source_path: &Path, link: &Path, target_path: &Path) -> io::Result<()> {
+    if source_path.is_dir() {
+        std::os::windows::fs::symlink_dir(link, target_path)
+    } else {
+        std::os::windows::fs::symlink_file(link, target_path)
+    }
+}
+
+#[cfg(not(windows))]
+pub(crate) fn create_symlink(_source_path: &Path, link: &Path, target_path: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(link, target_path)
+}
+
+fn copy_attributes(
+    source_metadata: &Metadata,
+    target_path: &Path,
+    options: &CopyOptions,
+) -> Result<(), FsIOError> {
+    if options.preserve_modified_time {
+        let mut times = FileTimes::new();
+        if let Ok(modified) = source_metadata.modified() {
+            times = times.set_modified(modified);
+        }
+        if let Ok(accessed) = source_metadata.accessed() {
+            times = times.set_accessed(accessed);
+        }
+
+        if let Err(error) = set_times(target_path, times) {
+            return Err(FsIOError::IOError(
+                    format!("Unable to set modified time for: {:?}", &target_path).to_string(),
+                    Some(error),
+                ),
+            );
+        }
+    }
+
+    if options.preserve_permissions {
+        if let Err(error) = set_permissions(target_path, source_metadata.permissions()) {
+            return Err(FsIOError::IOError(
+                    format!("Unable to set permissions for: {:?}", &target_path).to_string(),
+                    Some(error),
+                ),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn set_times(path: &Path, times: FileTimes) -> io::Result<()> {
+    use std::os::windows::fs::OpenOptionsExt;
+
+    // FILE_WRITE_ATTRIBUTES, FILE_FLAG_BACKUP_SEMANTICS (needed to open directories)
+    std::fs::OpenOptions::new()
+        .access_mode(0x100)
+        .custom_flags(0x02000000)
+        .open(path)?
+        .set_times(times)
+}
+
+#[cfg(not(windows))]
+fn set_times(path: &Path, times: FileTimes) -> io::Result<()> {
+    File::open(path)?.set_times(times)
+}
+
+/// Moves the source directory to the target path.
+/// The directory is renamed if possible and in case the target is located on a different
+/// file system, the directory tree is copied, verified against the source and only then
+/// the source is deleted.
+/// The target parent directories are created if needed and an error is returned if the
+/// target already exists and is not an empty directory.
+///
+/// # Arguments
+///
+/// * `source` - The source directory path
+/// * `target` - The target directory path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::{directory, file};
+/// use std::path::Path;
+///
+/// fn main() {
+///     file::write_text_file("./target/__test/directory_test/move_dir/source/file.txt", "text").unwrap();
+///
+///     directory::move_dir(
+///         "./target/__test/directory_test/move_dir/source",
+///         "./target/__test/directory_test/move_dir/dir/target",
+///     )
+///     .unwrap();
+///
+///     assert!(!Path::new("./target/__test/directory_test/move_dir/source").exists());
+///     assert!(Path::new("./target/__test/directory_test/move_dir/dir/target/file.txt").exists());
+/// }
+/// ```
+pub fn move_dir<S: AsPath + ?Sized, D: AsPath + ?Sized>(
+    source: &S,
+    target: &D,
+) -> Result<(), FsIOError> {
+    let source_path = source.as_path();
+    let target_path = target.as_path();
+
+    if !source_path.is_dir() {
+        return Err(FsIOError::NotDirectory(
+                format!("Path: {:?} is not a directory.", &source_path).to_string(),
+            ),
+        );
+    }
+
+    validate_move_target(target_path)?;
+    create_parent(&target_path)?;
+
+    match rename(source_path, target_path) {
+        Ok(_) => Ok(()),
+        Err(ref error) if is_cross_device_error(error) => {
+            move_directory_by_copy(source_path, target_path)
+        }
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to move: {:?} to: {:?}", &source_path, &target_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+fn move_directory_by_copy(source_path: &Path, target_path: &Path) -> Result<(), FsIOError> {
+    // checked again as the target could have been created after the rename attempt
+    validate_move_target(target_path)?;
+    let target_existed = target_path.exists();
+
+    let mut options = CopyOptions::new();
+    options.overwrite = OverwritePolicy::Error;
+    options.preserve_modified_time = true;
+
+    let result = copy(&source_path, &target_path, &options)
+        .and_then(|_| verify_directory_copy(source_path, target_path));
+    if let Err(error) = result {
+        // only clean up what was created by the copy
+        if !target_existed {
+            remove_dir_all(target_path).unwrap_or(());
+        }
+
+        return Err(error);
+    }
+
+    match remove_dir_all(source_path) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to delete directory: {:?}", &source_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Validates the move target does not exist or is an empty directory, same as rename requires,
+/// so the moved directory is never merged into an existing one.
+fn validate_move_target(target_path: &Path) -> Result<(), FsIOError> {
+    let target_metadata = match symlink_metadata(target_path) {
+        Ok(value) => value,
+        Err(ref error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read metadata for: {:?}", &target_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    let empty_directory = target_metadata.is_dir()
+        && match read_dir(target_path) {
+            Ok(mut entries) => entries.next().is_none(),
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read directory: {:?}", &target_path).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        };
+
+    if empty_directory {
+        Ok(())
+    } else {
+        Err(FsIOError::PathAlreadyExists(
+                format!("Target path: {:?} already exists.", &target_path).to_string(),
+            ),
+        )
+    }
+}
+
+/// Validates that every source entry exists in the target with the same type and content.
+fn verify_directory_copy(source_path: &Path, target_path: &Path) -> Result<(), FsIOError> {
+    let mut options = WalkOptions::new();
+    options.min_depth = 1;
+
+    for entry in walk_with_options(&source_path, &options) {
+        let entry = entry?;
+        let relative_path = match entry.path().strip_prefix(source_path) {
+            Ok(relative_path) => relative_path,
+            Err(_) => continue,
+        };
+        let target_entry_path = target_path.join(relative_path);
+
+        let file_type = entry.file_type();
+        let valid = match symlink_metadata(&target_entry_path) {
+            Ok(target_metadata) => {
+                if file_type.is_symlink() {
+                    target_metadata.file_type().is_symlink()
+                        && read_link(entry.path()).ok() == read_link(&target_entry_path).ok()
+                } else if file_type.is_dir() {
+                    target_metadata.is_dir()
+                } else {
+                    target_metadata.is_file()
+                        && crate::file::is_same_content(entry.path(), &target_entry_path)?
+                }
+            }
+            Err(_) => false,
+        };
+
+        if !valid {
+            return Err(FsIOError::IOError(
+                    format!(
+                        "Copied path: {:?} does not match source: {:?}",
+                        &target_entry_path,
+                        entry.path()
+                    )
+                    .to_string(),
+                    None,
+                ),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Defines the order in which the directory tree is walked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalkOrder {
+    /// Directory content is returned right after the directory itself
+    DepthFirst,
+    /// All entries of a given depth are returned before moving to the next depth
+    BreadthFirst,
+}
+
+/// Holds the directory walk options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalkOptions {
+    /// The walk order (default depth first)
+    pub order: WalkOrder,
+    /// Entries with a lower depth are not returned, the root has depth 0 (default 0)
+    pub min_depth: usize,
+    /// Entries with a higher depth are not returned nor walked (default none)
+    pub max_depth: Option<usize>,
+    /// True to walk into symbolic links pointing to directories (default false).
+    /// Cycles are detected and returned as errors.
+    pub follow_symlinks: bool,
+    /// True to return the entries of every directory sorted by file name (default false)
+    pub sort: bool,
+}
+
+impl WalkOptions {
+    /// Returns new instance with default values.
+    pub fn new() -> WalkOptions {
+        WalkOptions {
+            order: WalkOrder::DepthFirst,
+            min_depth: 0,
+            max_depth: None,
+            follow_symlinks: false,
+            sort: false,
+        }
+    }
+}
+
+impl Default for WalkOptions {
+    fn default() -> Self {
+        WalkOptions::new()
+    }
+}
+
+/// A single entry returned by the directory walk.
+#[derive(Debug)]
+pub struct WalkEntry {
+    path: PathBuf,
+    depth: usize,
+    metadata: Metadata,
+}
+
+impl WalkEntry {
+    /// Returns the entry path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the entry depth, the root has depth 0.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the entry file type.
+    /// Symbolic links are only resolved if the walk follows symbolic links.
+    pub fn file_type(&self) -> FileType {
+        self.metadata.file_type()
+    }
+
+    /// Returns the entry metadata.
+    /// Symbolic links are only resolved if the walk follows symbolic links.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+}
+
+#[cfg(windows)]
+type DirectoryId = PathBuf;
+
+#[cfg(not(windows))]
+type DirectoryId = (u64, u64);
+
+#[cfg(windows)]
+fn get_directory_id(path: &Path, _metadata: &Metadata) -> Option<DirectoryId> {
+    path.canonicalize().ok()
+}
+
+#[cfg(not(windows))]
+fn get_directory_id(_path: &Path, metadata: &Metadata) -> Option<DirectoryId> {
+    use std::os::unix::fs::MetadataExt;
+
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[derive(Debug)]
+struct WalkItem {
+    path: PathBuf,
+    depth: usize,
+    ancestors: Vec<DirectoryId>,
+}
+
+/// Iterator over a directory tree.
+/// Created via the [walk](fn.walk.html) and [walk_with_options](fn.walk_with_options.html)
+/// functions.
+#[derive(Debug)]
+pub struct Walk {
+    options: WalkOptions,
+    items: VecDeque<WalkItem>,
+    errors: VecDeque<FsIOError>,
+    current_directory: Option<WalkItem>,
+    root: bool,
+}
+
+impl Walk {
+    /// Skips the content of the last returned directory.
+    /// Calling this function after a non directory entry was returned has no effect.
+    pub fn skip_current_dir(&mut self) {
+        self.current_directory = None;
+    }
+
+    fn read_directory(&mut self, directory: WalkItem) {
+        let entries = match read_dir(&directory.path) {
+            Ok(entries) => entries,
+            Err(error) => {
+                self.errors.push_back(FsIOError::IOError(
+                        format!("Unable to read directory: {:?}", &directory.path).to_string(),
+                        Some(error),
+                    ),
+                );
+                return;
+            }
+        };
+
+        let mut paths = vec![];
+        for entry in entries {
+            match entry {
+                Ok(entry) => paths.push(entry.path()),
+                Err(error) => self.errors.push_back(FsIOError::IOError(
+                        format!("Unable to read directory: {:?}", &directory.path).to_string(),
+                        Some(error),
+                    ),
+                ),
+            }
+        }
+
+        if self.options.sort {
+            paths.sort_by(|first, second| first.file_name().cmp(&second.file_name()));
+        }
+
+        let depth = directory.depth + 1;
+        let items = paths.into_iter().map(|path| WalkItem {
+            path,
+            depth,
+            ancestors: directory.ancestors.clone(),
+        });
+        match self.options.order {
+            WalkOrder::DepthFirst => {
+                for item in items.rev() {
+                    self.items.push_front(item);
+                }
+            }
+            WalkOrder::BreadthFirst => self.items.extend(items),
+        }
+    }
+}
+
+impl Iterator for Walk {
+    type Item = Result<WalkEntry, FsIOError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(directory) = self.current_directory.take() {
+                self.read_directory(directory);
+            }
+
+            if let Some(error) = self.errors.pop_front() {
+                return Some(Err(error));
+            }
+
+            let WalkItem {
+                path,
+                depth,
+                mut ancestors,
+            } = self.items.pop_front()?;
+
+            // the root is always resolved so walking a symbolic link to a directory works
+            let metadata_result = if self.options.follow_symlinks || self.root {
+                metadata(&path)
+            } else {
+                symlink_metadata(&path)
+            };
+            self.root = false;
+
+            let item_metadata = match metadata_result {
+                Ok(value) => value,
+                Err(error) => {
+                    return Some(Err(FsIOError::IOError(
+                            format!("Unable to read metadata for: {:?}", &path).to_string(),
+                            Some(error),
+                        ),
+                    ))
+                }
+            };
+
+            let walk_into = item_metadata.is_dir()
+                && self
+                    .options
+                    .max_depth
+                    .map_or(true, |max_depth| depth < max_depth);
+            if walk_into {
+                if self.options.follow_symlinks {
+                    if let Some(id) = get_directory_id(&path, &item_metadata) {
+                        if ancestors.contains(&id) {
+                            return Some(Err(FsIOError::IOError(
+                                    format!("Directory cycle detected at: {:?}", &path)
+                                        .to_string(),
+                                    None,
+                                ),
+                            ));
+                        }
+                        ancestors.push(id);
+                    }
+                }
+
+                self.current_directory = Some(WalkItem {
+                    path: path.clone(),
+                    depth,
+                    ancestors,
+                });
+            }
+
+            if depth >= self.options.min_depth {
+                return Some(Ok(WalkEntry {
+                    path,
+                    depth,
+                    metadata: item_metadata,
+                }));
+            }
+        }
+    }
+}
+
+/// Returns an iterator which walks the directory tree depth first, starting with the
+/// provided path itself.
+/// Errors (for example unreadable directories) are returned as items and do not stop the walk.
+///
+/// # Arguments
+///
+/// * `path` - The root directory path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::{directory, file};
+///
+/// fn main() {
+///     file::ensure_exists("./target/__test/directory_test/walk/dir1/file.txt").unwrap();
+///
+///     let count = directory::walk("./target/__test/directory_test/walk")
+///         .filter_map(|entry| entry.ok())
+///         .filter(|entry| entry.file_type().is_file())
+///         .count();
+///
+///     assert_eq!(count, 1);
+/// }
+/// ```
+pub fn walk<T: AsPath + ?Sized>(path: &T) -> Walk {
+    walk_with_options(path, &WalkOptions::new())
+}
+
+/// Returns an iterator which walks the directory tree based on the provided options.
+/// Subtrees can be pruned while iterating by calling
+/// [skip_current_dir](struct.Walk.html#method.skip_current_dir).
+///
+/// # Arguments
+///
+/// * `path` - The root directory path
+/// * `options` - The walk options
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::{directory, file};
+/// use crate::fsio::directory::{WalkOptions, WalkOrder};
+///
+/// fn main() {
+///     file::ensure_exists("./target/__test/directory_test/walk_with_options/a/b/file.txt").unwrap();
+///     file::ensure_exists("./target/__test/directory_test/walk_with_options/c.txt").unwrap();
+///
+///     let mut options = WalkOptions::new();
+///     options.order = WalkOrder::BreadthFirst;
+///     options.min_depth = 1;
+///     options.max_depth = Some(2);
+///     options.sort = true;
+///     let depths: Vec<usize> = directory::walk_with_options("./target/__test/directory_test/walk_with_options", &options)
+///         .map(|entry| entry.unwrap().depth())
+///         .collect();
+///
+///     assert_eq!(depths, vec![1, 1, 2]);
+/// }
+/// ```
+pub fn walk_with_options<T: AsPath + ?Sized>(path: &T, options: &WalkOptions) -> Walk {
+    let mut items = VecDeque::new();
+    items.push_back(WalkItem {
+        path: path.as_path().to_path_buf(),
+        depth: 0,
+        ancestors: vec![],
+    });
+
+    Walk {
+        options: *options,
+        items,
+        errors: VecDeque::new(),
+        current_directory: None,
+        root: true,
+    }
+}
+
+/// Holds the directory hash options.
+#[cfg(feature = "hash")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashOptions {
+    /// The hash algorithm (default SHA-256)
+    pub algorithm: HashAlgorithm,
+    /// True to include the entries permissions in the digest (default false).
+    /// On windows only the read only flag is included.
+    pub include_modes: bool,
+}
+
+#[cfg(feature = "hash")]
+impl HashOptions {
+    /// Returns new instance with default values.
+    pub fn new() -> HashOptions {
+        HashOptions {
+            algorithm: HashAlgorithm::Sha256,
+            include_modes: false,
+        }
+    }
+}
+
+#[cfg(feature = "hash")]
+impl Default for HashOptions {
+    fn default() -> Self {
+        HashOptions::new()
+    }
+}
+
+/// Returns a deterministic SHA-256 digest (lower case hex string) of the whole directory tree.
+/// The digest covers the relative paths, entry types, file contents and symbolic link targets,
+/// so it does not change when the tree is copied to another location.
+/// Special files (fifo, socket, device) are only hashed by path and type, their content is not
+/// read.
+///
+/// # Arguments
+///
+/// * `path` - The root directory path
+///
+/// # Feature
+///
+/// This function requires that the **hash** feature will be used.
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::{directory, file};
+///
+/// fn main() {
+///     file::write_text_file("./target/__test/directory_test/hash/1/dir/file.txt", "content").unwrap();
+///     file::write_text_file("./target/__test/directory_test/hash/2/dir/file.txt", "content").unwrap();
+///
+///     let first = directory::hash("./target/__test/directory_test/hash/1").unwrap();
+///     let second = directory::hash("./target/__test/directory_test/hash/2").unwrap();
+///
+///     assert_eq!(first, second);
+/// }
+/// ```
+#[cfg(feature = "hash")]
+pub fn hash<T: AsPath + ?Sized>(path: &T) -> Result<String, FsIOError> {
+    hash_with_options(path, &HashOptions::new())
+}
+
+/// Returns a deterministic digest (lower case hex string) of the whole directory tree
+/// based on the provided options.
+///
+/// # Arguments
+///
+/// * `path` - The root directory path
+/// * `options` - The hash options
+///
+/// # Feature
+///
+/// This function requires that the **hash** feature will be used.
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::{directory, file};
+/// use crate::fsio::directory::HashOptions;
+/// use crate::fsio::file::HashAlgorithm;
+///
+/// fn main() {
+///     file::write_text_file("./target/__test/directory_test/hash_with_options/file.txt", "content").unwrap();
+///
+///     let mut options = HashOptions::new();
+///     options.algorithm = HashAlgorithm::Md5;
+///     options.include_modes = true;
+///     let digest = directory::hash_with_options("./target/__test/directory_test/hash_with_options", &options).unwrap();
+///
+///     assert_eq!(digest.len(), 32);
+/// }
+/// ```
+#[cfg(feature = "hash")]
+pub fn hash_with_options<T: AsPath + ?Sized>(
+    path: &T,
+    options: &HashOptions,
+) -> Result<String, FsIOError> {
+    let root = path.as_path();
+    match metadata(root) {
+        Ok(root_metadata) => {
+            if !root_metadata.is_dir() {
+                return Err(FsIOError::NotDirectory(
+                        format!("Path: {:?} is not a directory.", &root).to_string(),
+                    ),
+                );
+            }
+        }
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read metadata for: {:?}", &root).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    let mut walk_options = WalkOptions::new();
+    walk_options.min_depth = 1;
+    walk_options.sort = true;
+
+    let mut hasher = Hasher::new(options.algorithm);
+    for entry in walk_with_options(&root, &walk_options) {
+        let entry = entry?;
+
+        let relative_path = match get_relative_path(root, entry.path()) {
+            Some(relative_path) => relative_path,
+            None => continue,
+        };
+
+        let file_type = entry.file_type();
+        let (entry_type, content) = if file_type.is_symlink() {
+            match read_link(entry.path()) {
+                Ok(target) => ("l", target.to_string_lossy().into_owned()),
+                Err(error) => {
+                    return Err(FsIOError::IOError(
+                            format!("Unable to read symbolic link: {:?}", entry.path()).to_string(),
+                            Some(error),
+                        ),
+                    )
+                }
+            }
+        } else if file_type.is_dir() {
+            ("d", "".to_string())
+        } else if file_type.is_file() {
+            ("f", hash_file(&entry.path(), options.algorithm)?)
+        } else {
+            // special files (fifo, socket, device) are not read as reading may block forever
+            ("s", "".to_string())
+        };
+
+        hasher.update(entry_type.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(relative_path.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(content.as_bytes());
+        if options.include_modes {
+            hasher.update(&[0]);
+            hasher.update(get_mode(entry.metadata()).as_bytes());
+        }
+        hasher.update(b"\n");
+    }
+
+    Ok(hasher.finish())
+}
+
+/// Returns the relative path using '/' as separator so it is the same on all platforms.
+#[cfg(feature = "hash")]
+fn get_relative_path(root: &Path, path: &Path) -> Option<String> {
+    match path.strip_prefix(root) {
+        Ok(relative_path) => Some(
+            relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+        ),
+        Err(_) => None,
+    }
+}
+
+#[cfg(all(feature = "hash", windows))]
+fn get_mode(metadata: &Metadata) -> String {
+    if metadata.permissions().readonly() {
+        "r".to_string()
+    } else {
+        "w".to_string()
+    }
+}
+
+#[cfg(all(feature = "hash", not(windows)))]
+fn get_mode(metadata: &Metadata) -> String {
+    use std::os::unix::fs::PermissionsExt;
+
+    format!("{:o}", metadata.permissions().mode() & 0o7777)
+}
+
+/// Holds the result of a manifest verification.
+/// All paths are relative to the manifest directory, as written in the manifest.
+#[cfg(feature = "hash")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestReport {
+    /// Files listed in the manifest which do not exist
+    pub missing: Vec<String>,
+    /// Files which exist but are not listed in the manifest
+    pub extra: Vec<String>,
+    /// Files which content does not match the manifest checksum
+    pub mismatched: Vec<String>,
+}
+
+#[cfg(feature = "hash")]
+impl ManifestReport {
+    /// Returns true if no missing, extra or mismatched files were found.
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.mismatched.is_empty()
+    }
+}
+
+/// Writes a sha256sum compatible manifest of all the files in the directory tree.
+/// Each file is streamed through SHA-256 and written as a `<checksum>  <relative path>` line,
+/// sorted by path and using '/' as separator.
+/// The manifest itself is skipped and only regular files are listed (symbolic links are not
+/// followed).
+/// Since [verify_manifest](fn.verify_manifest.html) resolves the paths relative to the manifest
+/// directory, the manifest must be written directly in the root directory, otherwise a
+/// PathOutsideBase error is returned.
+///
+/// # Arguments
+///
+/// * `root` - The root directory path
+/// * `out` - The manifest file path
+///
+/// # Feature
+///
+/// This function requires that the **hash** feature will be used.
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::{directory, file};
+///
+/// fn main() {
+///     file::write_text_file("./target/__test/directory_test/write_manifest/dir/file.txt", "some content").unwrap();
+///
+///     let manifest = "./target/__test/directory_test/write_manifest/SHA256SUMS";
+///     directory::write_manifest("./target/__test/directory_test/write_manifest", manifest).unwrap();
+///
+///     let text = file::read_text_file(manifest).unwrap();
+///     assert_eq!(text, "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56  dir/file.txt\n");
+/// }
+/// ```
+#[cfg(feature = "hash")]
+pub fn write_manifest<R: AsPath + ?Sized, O: AsPath + ?Sized>(
+    root: &R,
+    out: &O,
+) -> Result<(), FsIOError> {
+    let root_path = root.as_path();
+    let out_path = out.as_path();
+
+    let manifest_relative_path = get_manifest_relative_path(root_path, out_path)?;
+    match manifest_relative_path {
+        Some(ref relative_path) if !relative_path.contains('/') => (),
+        _ => {
+            return Err(FsIOError::PathOutsideBase(
+                    format!(
+                        "Manifest: {:?} is not located directly in the root directory: {:?}",
+                        &out_path, &root_path
+                    )
+                    .to_string(),
+                ),
+            )
+        }
+    };
+
+    let fd = match File::create(out_path) {
+        Ok(fd) => fd,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to create file: {:?}", &out_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    let files = list_manifest_files(root_path, manifest_relative_path)?;
+
+    let mut writer = BufWriter::new(fd);
+    for (relative_path, file_path) in files {
+        let digest = hash_file(&file_path, HashAlgorithm::Sha256)?;
+        let line = format_manifest_line(&digest, &relative_path);
+
+        if let Err(error) = writer.write_all(line.as_bytes()) {
+            return Err(FsIOError::IOError(
+                    format!("Unable to write to file: {:?}", &out_path).to_string(),
+                    Some(error),
+                ),
+            );
+        }
+    }
+
+    match writer.flush() {
+        Ok(_) => Ok(()),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to write to file: {:?}", &out_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Verifies the files in the manifest directory tree against a sha256sum compatible manifest.
+/// The paths in the manifest are normalized and resolved relative to the manifest directory,
+/// an error is returned for absolute paths or paths leading outside of the manifest directory.
+/// Missing, extra and mismatched files are returned in the report while an error is only
+/// returned if the manifest is invalid or the files can not be read.
+///
+/// # Arguments
+///
+/// * `manifest` - The manifest file path
+///
+/// # Feature
+///
+/// This function requires that the **hash** feature will be used.
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::{directory, file};
+///
+/// fn main() {
+///     file::write_text_file("./target/__test/directory_test/verify_manifest/file.txt", "some content").unwrap();
+///
+///     let manifest = "./target/__test/directory_test/verify_manifest/SHA256SUMS";
+///     directory::write_manifest("./target/__test/directory_test/verify_manifest", manifest).unwrap();
+///
+///     let report = directory::verify_manifest(manifest).unwrap();
+///     assert!(report.is_valid());
+///
+///     file::write_text_file("./target/__test/directory_test/verify_manifest/file.txt", "changed").unwrap();
+///
+///     let report = directory::verify_manifest(manifest).unwrap();
+///     assert_eq!(report.mismatched, vec!["file.txt".to_string()]);
+/// }
+/// ```
+#[cfg(feature = "hash")]
+pub fn verify_manifest<T: AsPath + ?Sized>(manifest: &T) -> Result<ManifestReport, FsIOError> {
+    let manifest_path = manifest.as_path();
+    let text = crate::file::read_text_file(&manifest_path)?;
+
+    let root_path = match get_parent_directory(&manifest_path) {
+        Some(directory) => PathBuf::from(directory),
+        None => PathBuf::from("."),
+    };
+
+    let mut entries = vec![];
+    for line in text.lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        match parse_manifest_line(line) {
+            Some((digest, relative_path)) => entries.push((
+                digest,
+                normalize_manifest_path(manifest_path, &relative_path)?,
+            )),
+            None => {
+                return Err(FsIOError::IOError(
+                        format!("Invalid manifest: {:?} line: {}", &manifest_path, line).to_string(),
+                        None,
+                    ),
+                )
+            }
+        }
+    }
+
+    let manifest_relative_path = get_manifest_relative_path(&root_path, manifest_path)?;
+    let files = list_manifest_files(&root_path, manifest_relative_path)?;
+
+    let mut report = ManifestReport::default();
+    for (digest, relative_path) in &entries {
+        let file_path = root_path.join(relative_path);
+        if !file_path.is_file() {
+            report.missing.push(relative_path.to_string());
+        } else if !hash_file(&file_path, HashAlgorithm::Sha256)?
+            .eq_ignore_ascii_case(digest)
+        {
+            report.mismatched.push(relative_path.to_string());
+        }
+    }
+
+    let listed_paths: HashSet<&String> = entries.iter().map(|(_, path)| path).collect();
+    for (relative_path, _) in files {
+        if !listed_paths.contains(&relative_path) {
+            report.extra.push(relative_path);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Returns the normalized manifest entry path using '/' as separator, so it can be compared
+/// with the listed files, failing for entries leading outside of the manifest directory.
+#[cfg(feature = "hash")]
+fn normalize_manifest_path(manifest: &Path, relative_path: &str) -> Result<String, FsIOError> {
+    let normalized_path: PathBuf = normalize(relative_path);
+
+    let mut components = vec![];
+    for component in normalized_path.components() {
+        match component {
+            Component::Normal(value) => components.push(value.to_string_lossy()),
+            _ => {
+                return Err(FsIOError::PathOutsideBase(
+                        format!(
+                            "Manifest: {:?} path: {:?} leads outside of the manifest directory.",
+                            &manifest, relative_path
+                        )
+                        .to_string(),
+                    ),
+                )
+            }
+        }
+    }
+
+    Ok(components.join("/"))
+}
+
+/// Returns the manifest path relative to the root in case it is located inside the tree.
+#[cfg(feature = "hash")]
+fn get_manifest_relative_path(root: &Path, manifest: &Path) -> Result<Option<String>, FsIOError> {
+    let root_path = canonicalize(root)?;
+
+    let manifest_directory = match get_parent_directory(&manifest) {
+        Some(directory) => PathBuf::from(directory),
+        None => PathBuf::from("."),
+    };
+    let manifest_path = match manifest.file_name() {
+        Some(file_name) => canonicalize(&manifest_directory)?.join(file_name),
+        None => return Ok(None),
+    };
+
+    Ok(get_relative_path(&root_path, &manifest_path))
+}
+
+/// Returns all regular files in the tree as sorted (relative path, path) pairs.
+#[cfg(feature = "hash")]
+fn list_manifest_files(
+    root: &Path,
+    skip_relative_path: Option<String>,
+) -> Result<Vec<(String, PathBuf)>, FsIOError> {
+    let mut walk_options = WalkOptions::new();
+    walk_options.min_depth = 1;
+
+    let mut files = vec![];
+    for entry in walk_with_options(&root, &walk_options) {
+        let entry = entry?;
+
+        if entry.file_type().is_file() {
+            if let Some(relative_path) = get_relative_path(root, entry.path()) {
+                if skip_relative_path.as_ref() != Some(&relative_path) {
+                    files.push((relative_path, entry.path().to_path_buf()));
+                }
+            }
+        }
+    }
+
+    // sort by the full relative path so the order does not depend on the walk order
+    files.sort();
+
+    Ok(files)
+}
+
+/// Formats a manifest line, escaping the path the same way sha256sum does.
+#[cfg(feature = "hash")]
+fn format_manifest_line(digest: &str, relative_path: &str) -> String {
+    if relative_path.contains(&['\\', '\n', '\r'][..]) {
+        let escaped_path = relative_path
+            .replace('\\', "\\\\")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r");
+
+        format!("\\{}  {}\n", digest, escaped_path)
+    } else {
+        format!("{}  {}\n", digest, relative_path)
+    }
+}
+
+/// Parses a sha256sum manifest line (text or binary mode) into (checksum, relative path).
+#[cfg(feature = "hash")]
+fn parse_manifest_line(line: &str) -> Option<(String, String)> {
+    let (escaped, line) = match line.strip_prefix('\\') {
+        Some(line) => (true, line),
+        None => (false, line),
+    };
+
+    let separator = line.find(' ')?;
+    let (digest, rest) = line.split_at(separator);
+    if digest.len() != 64 || !digest.chars().all(|character| character.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let relative_path = match rest.strip_prefix("  ").or_else(|| rest.strip_prefix(" *")) {
+        Some(relative_path) if !relative_path.is_empty() => relative_path,
+        _ => return None,
+    };
+
+    if !escaped {
+        return Some((digest.to_string(), relative_path.to_string()));
+    }
+
+    let mut unescaped_path = String::new();
+    let mut characters = relative_path.chars();
+    while let Some(character) = characters.next() {
+        if character == '\\' {
+            match characters.next()? {
+                '\\' => unescaped_path.push('\\'),
+                'n' => unescaped_path.push('\n'),
+                'r' => unescaped_path.push('\r'),
+                _ => return None,
+            }
+        } else {
+            unescaped_path.push(character);
+        }
+    }
+
+    Some((digest.to_string(), unescaped_path))
+}
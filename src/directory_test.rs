@@ -1,7 +1,9 @@
 use super::*;
 
-use crate::file::ensure_exists;
+use crate::error::FsIOError;
+use crate::file::{ensure_exists, read_text_file, write_text_file};
 use std::fs::File;
+use std::time::{Duration, SystemTime};
 use std::path::Path;
 
 #[test]
@@ -120,3 +122,797 @@ fn delete_file() {
 
     assert!(path.exists());
 }
+
+#[test]
+fn copy_not_exists() {
+    let result = copy(
+        "./target/__test/ut/directory_test/copy/copy_not_exists/source",
+        "./target/__test/ut/directory_test/copy/copy_not_exists/target",
+        &CopyOptions::new(),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn copy_on_file() {
+    let file_path = "./target/__test/ut/directory_test/copy/copy_on_file/file.txt";
+    ensure_exists(file_path).unwrap();
+
+    let result = copy(
+        file_path,
+        "./target/__test/ut/directory_test/copy/copy_on_file/target",
+        &CopyOptions::new(),
+    );
+
+    match result {
+        Err(FsIOError::NotDirectory(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+fn copy_tree() {
+    let source = "./target/__test/ut/directory_test/copy/copy_tree/source";
+    write_text_file(&format!("{}/file1.txt", source), "1").unwrap();
+    write_text_file(&format!("{}/dir1/file2.txt", source), "2").unwrap();
+    write_text_file(&format!("{}/dir1/dir2/file3.txt", source), "3").unwrap();
+    create(&format!("{}/empty", source)).unwrap();
+
+    let target = "./target/__test/ut/directory_test/copy/copy_tree/target";
+    let result = copy(source, target, &CopyOptions::new());
+    assert!(result.is_ok());
+
+    assert_eq!(read_text_file(&format!("{}/file1.txt", target)).unwrap(), "1");
+    assert_eq!(read_text_file(&format!("{}/dir1/file2.txt", target)).unwrap(), "2");
+    assert_eq!(read_text_file(&format!("{}/dir1/dir2/file3.txt", target)).unwrap(), "3");
+    assert!(Path::new(&format!("{}/empty", target)).is_dir());
+}
+
+#[test]
+fn copy_into_itself() {
+    let source = "./target/__test/ut/directory_test/copy/copy_into_itself/source";
+    write_text_file(&format!("{}/file.txt", source), "1").unwrap();
+
+    let target = "./target/__test/ut/directory_test/copy/copy_into_itself/source/target";
+    let result = copy(source, target, &CopyOptions::new());
+    assert!(result.is_ok());
+
+    assert_eq!(read_text_file(&format!("{}/file.txt", target)).unwrap(), "1");
+    assert!(!Path::new(&format!("{}/target", target)).exists());
+}
+
+#[test]
+fn copy_overwrite_policy() {
+    let source = "./target/__test/ut/directory_test/copy/copy_overwrite_policy/source";
+    let target = "./target/__test/ut/directory_test/copy/copy_overwrite_policy/target";
+    write_text_file(&format!("{}/file.txt", source), "new").unwrap();
+    write_text_file(&format!("{}/file.txt", target), "old").unwrap();
+
+    let mut options = CopyOptions::new();
+    options.overwrite = OverwritePolicy::Error;
+    let mut result = copy(source, target, &options);
+    assert!(result.is_err());
+
+    options.overwrite = OverwritePolicy::Skip;
+    result = copy(source, target, &options);
+    assert!(result.is_ok());
+    assert_eq!(read_text_file(&format!("{}/file.txt", target)).unwrap(), "old");
+
+    options.overwrite = OverwritePolicy::Overwrite;
+    result = copy(source, target, &options);
+    assert!(result.is_ok());
+    assert_eq!(read_text_file(&format!("{}/file.txt", target)).unwrap(), "new");
+}
+
+#[test]
+fn copy_include_exclude() {
+    let source = "./target/__test/ut/directory_test/copy/copy_include_exclude/source";
+    write_text_file(&format!("{}/file.txt", source), "1").unwrap();
+    write_text_file(&format!("{}/file.log", source), "2").unwrap();
+    write_text_file(&format!("{}/skip/file.txt", source), "3").unwrap();
+    write_text_file(&format!("{}/dir/file.txt", source), "4").unwrap();
+
+    let mut options = CopyOptions::new();
+    options.include = Some(Box::new(|path: &Path| {
+        path.extension().map_or(false, |extension| extension == "txt")
+    }));
+    options.exclude = Some(Box::new(|path: &Path| path == Path::new("skip")));
+
+    let target = "./target/__test/ut/directory_test/copy/copy_include_exclude/target";
+    let result = copy(source, target, &options);
+    assert!(result.is_ok());
+
+    assert!(Path::new(&format!("{}/file.txt", target)).exists());
+    assert!(Path::new(&format!("{}/dir/file.txt", target)).exists());
+    assert!(!Path::new(&format!("{}/file.log", target)).exists());
+    assert!(!Path::new(&format!("{}/skip", target)).exists());
+}
+
+#[test]
+fn copy_preserve_modified_time() {
+    let source = "./target/__test/ut/directory_test/copy/copy_preserve_modified_time/source";
+    let source_file = format!("{}/file.txt", source);
+    write_text_file(&source_file, "1").unwrap();
+    let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+    File::options()
+        .write(true)
+        .open(&source_file)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+
+    let mut options = CopyOptions::new();
+    options.preserve_modified_time = true;
+    let target = "./target/__test/ut/directory_test/copy/copy_preserve_modified_time/target";
+    let result = copy(source, target, &options);
+    assert!(result.is_ok());
+
+    let target_modified = metadata(&format!("{}/file.txt", target))
+        .unwrap()
+        .modified()
+        .unwrap();
+    assert_eq!(target_modified, modified);
+}
+
+#[test]
+#[cfg(not(windows))]
+fn copy_symlinks() {
+    use std::os::unix::fs::symlink;
+
+    let root = "./target/__test/ut/directory_test/copy/copy_symlinks";
+    delete(root).unwrap();
+    let source = "./target/__test/ut/directory_test/copy/copy_symlinks/source";
+    write_text_file(&format!("{}/file.txt", source), "1").unwrap();
+    symlink("file.txt", &format!("{}/link.txt", source)).unwrap();
+
+    let mut options = CopyOptions::new();
+    let mut target = "./target/__test/ut/directory_test/copy/copy_symlinks/target1";
+    let mut result = copy(source, target, &options);
+    assert!(result.is_ok());
+
+    let mut link_path = format!("{}/link.txt", target);
+    assert!(symlink_metadata(&link_path).unwrap().file_type().is_symlink());
+    assert_eq!(read_link(&link_path).unwrap(), Path::new("file.txt"));
+
+    options.symlinks = SymlinkPolicy::Follow;
+    target = "./target/__test/ut/directory_test/copy/copy_symlinks/target2";
+    result = copy(source, target, &options);
+    assert!(result.is_ok());
+
+    link_path = format!("{}/link.txt", target);
+    assert!(symlink_metadata(&link_path).unwrap().is_file());
+    assert_eq!(read_text_file(&link_path).unwrap(), "1");
+}
+
+#[test]
+#[cfg(not(windows))]
+fn copy_follow_symlink_cycle() {
+    use std::os::unix::fs::symlink;
+
+    let root = "./target/__test/ut/directory_test/copy/copy_follow_symlink_cycle";
+    delete(root).unwrap();
+    let source = "./target/__test/ut/directory_test/copy/copy_follow_symlink_cycle/source";
+    write_text_file(&format!("{}/dir/file.txt", source), "1").unwrap();
+    symlink("..", &format!("{}/dir/parent", source)).unwrap();
+
+    let mut options = CopyOptions::new();
+    options.symlinks = SymlinkPolicy::Follow;
+    let target = "./target/__test/ut/directory_test/copy/copy_follow_symlink_cycle/target";
+    let result = copy(source, target, &options);
+    assert!(result.is_err());
+}
+
+fn walk_paths(walk: &mut Walk, root: &str) -> Vec<String> {
+    let mut paths = vec![];
+    while let Some(entry) = walk.next() {
+        let entry = entry.unwrap();
+        let relative = entry.path().strip_prefix(root).unwrap();
+        paths.push(relative.to_string_lossy().replace("\\", "/"));
+    }
+    paths
+}
+
+fn create_walk_tree(root: &str) {
+    ensure_exists(&format!("{}/a/a1.txt", root)).unwrap();
+    ensure_exists(&format!("{}/a/b/b1.txt", root)).unwrap();
+    ensure_exists(&format!("{}/c.txt", root)).unwrap();
+}
+
+#[test]
+fn walk_not_exists() {
+    let results: Vec<Result<WalkEntry, FsIOError>> =
+        walk("./target/__test/ut/directory_test/walk/walk_not_exists").collect();
+
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+}
+
+#[test]
+fn walk_file() {
+    let file_path = "./target/__test/ut/directory_test/walk/walk_file/file.txt";
+    ensure_exists(file_path).unwrap();
+
+    let entries: Vec<WalkEntry> = walk(file_path).map(|entry| entry.unwrap()).collect();
+
+    assert_eq!(entries.len(), 1);
+    assert!(entries[0].file_type().is_file());
+    assert_eq!(entries[0].depth(), 0);
+    assert_eq!(entries[0].path(), Path::new(file_path));
+}
+
+#[test]
+fn walk_depth_first_sorted() {
+    let root = "./target/__test/ut/directory_test/walk/walk_depth_first_sorted";
+    create_walk_tree(root);
+
+    let mut options = WalkOptions::new();
+    options.sort = true;
+    let paths = walk_paths(&mut walk_with_options(root, &options), root);
+
+    assert_eq!(paths, vec!["", "a", "a/a1.txt", "a/b", "a/b/b1.txt", "c.txt"]);
+}
+
+#[test]
+fn walk_breadth_first_sorted() {
+    let root = "./target/__test/ut/directory_test/walk/walk_breadth_first_sorted";
+    create_walk_tree(root);
+
+    let mut options = WalkOptions::new();
+    options.sort = true;
+    options.order = WalkOrder::BreadthFirst;
+    let paths = walk_paths(&mut walk_with_options(root, &options), root);
+
+    assert_eq!(paths, vec!["", "a", "c.txt", "a/a1.txt", "a/b", "a/b/b1.txt"]);
+}
+
+#[test]
+fn walk_min_max_depth() {
+    let root = "./target/__test/ut/directory_test/walk/walk_min_max_depth";
+    create_walk_tree(root);
+
+    let mut options = WalkOptions::new();
+    options.sort = true;
+    options.min_depth = 1;
+    options.max_depth = Some(2);
+    let paths = walk_paths(&mut walk_with_options(root, &options), root);
+
+    assert_eq!(paths, vec!["a", "a/a1.txt", "a/b", "c.txt"]);
+}
+
+#[test]
+fn walk_skip_current_dir() {
+    let root = "./target/__test/ut/directory_test/walk/walk_skip_current_dir";
+    create_walk_tree(root);
+
+    let mut options = WalkOptions::new();
+    options.sort = true;
+    let mut iterator = walk_with_options(root, &options);
+    let mut paths = vec![];
+    while let Some(entry) = iterator.next() {
+        let entry = entry.unwrap();
+        if entry.path().ends_with("b") {
+            iterator.skip_current_dir();
+        }
+        let relative = entry.path().strip_prefix(root).unwrap();
+        paths.push(relative.to_string_lossy().replace("\\", "/"));
+    }
+
+    assert_eq!(paths, vec!["", "a", "a/a1.txt", "a/b", "c.txt"]);
+}
+
+#[test]
+#[cfg(not(windows))]
+fn walk_symlinks() {
+    use std::os::unix::fs::symlink;
+
+    let root = "./target/__test/ut/directory_test/walk/walk_symlinks";
+    delete(root).unwrap();
+    ensure_exists(&format!("{}/dir/file.txt", root)).unwrap();
+    symlink("..", &format!("{}/dir/parent", root)).unwrap();
+
+    let mut options = WalkOptions::new();
+    options.sort = true;
+    let paths = walk_paths(&mut walk_with_options(root, &options), root);
+    assert_eq!(paths, vec!["", "dir", "dir/file.txt", "dir/parent"]);
+
+    options.follow_symlinks = true;
+    let results: Vec<Result<WalkEntry, FsIOError>> = walk_with_options(root, &options).collect();
+    assert_eq!(results.len(), 4);
+    assert!(results[3].is_err());
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_same_content_different_location() {
+    let root = "./target/__test/ut/directory_test/hash/hash_same_content_different_location";
+    for name in &["first", "second"] {
+        write_text_file(&format!("{}/{}/a.txt", root, name), "a").unwrap();
+        write_text_file(&format!("{}/{}/dir/b.txt", root, name), "b").unwrap();
+        create(&format!("{}/{}/empty", root, name)).unwrap();
+    }
+
+    let first = hash(&format!("{}/first", root)).unwrap();
+    let second = hash(&format!("{}/second", root)).unwrap();
+
+    assert_eq!(first.len(), 64);
+    assert_eq!(first, second);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_content_changed() {
+    let root = "./target/__test/ut/directory_test/hash/hash_content_changed";
+    write_text_file(&format!("{}/dir/file.txt", root), "1").unwrap();
+    let first = hash(root).unwrap();
+
+    write_text_file(&format!("{}/dir/file.txt", root), "2").unwrap();
+    let second = hash(root).unwrap();
+
+    assert_ne!(first, second);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_path_changed() {
+    let root = "./target/__test/ut/directory_test/hash/hash_path_changed";
+    write_text_file(&format!("{}/first/file1.txt", root), "1").unwrap();
+    write_text_file(&format!("{}/second/file2.txt", root), "1").unwrap();
+
+    let first = hash(&format!("{}/first", root)).unwrap();
+    let second = hash(&format!("{}/second", root)).unwrap();
+
+    assert_ne!(first, second);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_empty_directory_added() {
+    let root = "./target/__test/ut/directory_test/hash/hash_empty_directory_added";
+    delete(root).unwrap();
+    write_text_file(&format!("{}/file.txt", root), "1").unwrap();
+    let first = hash(root).unwrap();
+
+    create(&format!("{}/empty", root)).unwrap();
+    let second = hash(root).unwrap();
+
+    assert_ne!(first, second);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_with_options_algorithm() {
+    let root = "./target/__test/ut/directory_test/hash/hash_with_options_algorithm";
+    write_text_file(&format!("{}/file.txt", root), "1").unwrap();
+
+    let mut options = HashOptions::new();
+    options.algorithm = HashAlgorithm::Crc32;
+    let digest = hash_with_options(root, &options).unwrap();
+
+    assert_eq!(digest.len(), 8);
+}
+
+#[test]
+#[cfg(all(feature = "hash", not(windows)))]
+fn hash_with_options_include_modes() {
+    use std::fs::Permissions;
+    use std::os::unix::fs::PermissionsExt;
+
+    let root = "./target/__test/ut/directory_test/hash/hash_with_options_include_modes";
+    let file_path = format!("{}/file.txt", root);
+    write_text_file(&file_path, "1").unwrap();
+    set_permissions(&file_path, Permissions::from_mode(0o644)).unwrap();
+
+    let mut options = HashOptions::new();
+    let first_without_modes = hash_with_options(root, &options).unwrap();
+    options.include_modes = true;
+    let first = hash_with_options(root, &options).unwrap();
+
+    set_permissions(&file_path, Permissions::from_mode(0o755)).unwrap();
+
+    let second = hash_with_options(root, &options).unwrap();
+    options.include_modes = false;
+    let second_without_modes = hash_with_options(root, &options).unwrap();
+
+    assert_ne!(first, second);
+    assert_eq!(first_without_modes, second_without_modes);
+}
+
+#[test]
+#[cfg(all(feature = "hash", not(windows)))]
+fn hash_symlink_target() {
+    use std::os::unix::fs::symlink;
+
+    let root = "./target/__test/ut/directory_test/hash/hash_symlink_target";
+    delete(root).unwrap();
+    write_text_file(&format!("{}/first/file.txt", root), "1").unwrap();
+    write_text_file(&format!("{}/second/file.txt", root), "1").unwrap();
+    symlink("file.txt", &format!("{}/first/link", root)).unwrap();
+    symlink("other.txt", &format!("{}/second/link", root)).unwrap();
+
+    let first = hash(&format!("{}/first", root)).unwrap();
+    let second = hash(&format!("{}/second", root)).unwrap();
+
+    assert_ne!(first, second);
+}
+
+#[test]
+#[cfg(all(feature = "hash", not(windows)))]
+fn hash_special_file_not_read() {
+    use std::process::Command;
+
+    let root = "./target/__test/ut/directory_test/hash/hash_special_file_not_read";
+    delete(root).unwrap();
+    write_text_file(&format!("{}/first/file.txt", root), "1").unwrap();
+    write_text_file(&format!("{}/second/file.txt", root), "1").unwrap();
+    let status = Command::new("mkfifo")
+        .arg(&format!("{}/first/fifo", root))
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let first = hash(&format!("{}/first", root)).unwrap();
+    let second = hash(&format!("{}/second", root)).unwrap();
+
+    assert_ne!(first, second);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_not_directory() {
+    let file_path = "./target/__test/ut/directory_test/hash/hash_not_directory/file.txt";
+    write_text_file(file_path, "1").unwrap();
+
+    let result = hash(file_path);
+
+    match result {
+        Err(FsIOError::NotDirectory(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn write_manifest_sorted_lines() {
+    let root = "./target/__test/ut/directory_test/manifest/write_manifest_sorted_lines";
+    write_text_file(&format!("{}/tree/b.txt", root), "some content").unwrap();
+    write_text_file(&format!("{}/tree/a/file.txt", root), "").unwrap();
+    create(&format!("{}/tree/empty", root)).unwrap();
+    let manifest = format!("{}/tree/SHA256SUMS", root);
+
+    write_manifest(&format!("{}/tree", root), &manifest).unwrap();
+
+    let text = read_text_file(&manifest).unwrap();
+    assert_eq!(
+        text,
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  a/file.txt\n\
+         290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56  b.txt\n"
+    );
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn write_manifest_skips_itself() {
+    let root = "./target/__test/ut/directory_test/manifest/write_manifest_skips_itself";
+    write_text_file(&format!("{}/file.txt", root), "some content").unwrap();
+    let manifest = format!("{}/SHA256SUMS", root);
+
+    write_manifest(root, &manifest).unwrap();
+    write_manifest(root, &manifest).unwrap();
+
+    let text = read_text_file(&manifest).unwrap();
+    assert_eq!(
+        text,
+        "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56  file.txt\n"
+    );
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn write_manifest_not_found() {
+    let root = "./target/__test/ut/directory_test/manifest/write_manifest_not_found";
+
+    let result = write_manifest(&format!("{}/tree", root), &format!("{}/SHA256SUMS", root));
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn write_manifest_outside_root() {
+    let root = "./target/__test/ut/directory_test/manifest/write_manifest_outside_root";
+    delete(root).unwrap();
+    write_text_file(&format!("{}/tree/dir/file.txt", root), "some content").unwrap();
+
+    for manifest in &[
+        format!("{}/SHA256SUMS", root),
+        format!("{}/tree/dir/SHA256SUMS", root),
+    ] {
+        let result = write_manifest(&format!("{}/tree", root), manifest);
+
+        match result {
+            Err(FsIOError::PathOutsideBase(_)) => (),
+            _ => panic!("Invalid result: {:?}", result),
+        }
+        assert!(!Path::new(manifest).exists());
+    }
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn verify_manifest_valid() {
+    let root = "./target/__test/ut/directory_test/manifest/verify_manifest_valid";
+    write_text_file(&format!("{}/file.txt", root), "1").unwrap();
+    write_text_file(&format!("{}/dir/file.txt", root), "2").unwrap();
+    let manifest = format!("{}/SHA256SUMS", root);
+    write_manifest(root, &manifest).unwrap();
+
+    let report = verify_manifest(&manifest).unwrap();
+
+    assert!(report.is_valid());
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn verify_manifest_invalid() {
+    let root = "./target/__test/ut/directory_test/manifest/verify_manifest_invalid";
+    delete(root).unwrap();
+    write_text_file(&format!("{}/missing.txt", root), "1").unwrap();
+    write_text_file(&format!("{}/mismatched.txt", root), "2").unwrap();
+    write_text_file(&format!("{}/valid.txt", root), "3").unwrap();
+    let manifest = format!("{}/SHA256SUMS", root);
+    write_manifest(root, &manifest).unwrap();
+
+    remove_file(&format!("{}/missing.txt", root)).unwrap();
+    write_text_file(&format!("{}/mismatched.txt", root), "changed").unwrap();
+    write_text_file(&format!("{}/dir/extra.txt", root), "4").unwrap();
+
+    let report = verify_manifest(&manifest).unwrap();
+
+    assert!(!report.is_valid());
+    assert_eq!(report.missing, vec!["missing.txt".to_string()]);
+    assert_eq!(report.extra, vec!["dir/extra.txt".to_string()]);
+    assert_eq!(report.mismatched, vec!["mismatched.txt".to_string()]);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn verify_manifest_binary_mode_and_upper_case() {
+    let root = "./target/__test/ut/directory_test/manifest/verify_manifest_binary_mode_and_upper_case";
+    write_text_file(&format!("{}/file.txt", root), "some content").unwrap();
+    let manifest = format!("{}/SHA256SUMS", root);
+    write_text_file(
+        &manifest,
+        "290F493C44F5D63D06B374D0A5ABD292FAE38B92CAB2FAE5EFEFE1B0E9347F56 *file.txt\n",
+    )
+    .unwrap();
+
+    let report = verify_manifest(&manifest).unwrap();
+
+    assert!(report.is_valid());
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn verify_manifest_invalid_line() {
+    let root = "./target/__test/ut/directory_test/manifest/verify_manifest_invalid_line";
+    let manifest = format!("{}/SHA256SUMS", root);
+    write_text_file(&manifest, "1234  file.txt\n").unwrap();
+
+    let result = verify_manifest(&manifest);
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn verify_manifest_current_directory_prefix() {
+    let root =
+        "./target/__test/ut/directory_test/manifest/verify_manifest_current_directory_prefix";
+    write_text_file(&format!("{}/dir/file.txt", root), "some content").unwrap();
+    let manifest = format!("{}/SHA256SUMS", root);
+    write_text_file(
+        &manifest,
+        "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56  ./dir/./file.txt\n",
+    )
+    .unwrap();
+
+    let report = verify_manifest(&manifest).unwrap();
+
+    assert!(report.is_valid());
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn verify_manifest_parent_directory() {
+    let root = "./target/__test/ut/directory_test/manifest/verify_manifest_parent_directory";
+    write_text_file(&format!("{}/file.txt", root), "some content").unwrap();
+    let manifest = format!("{}/tree/SHA256SUMS", root);
+    write_text_file(
+        &manifest,
+        "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56  ../file.txt\n",
+    )
+    .unwrap();
+
+    let result = verify_manifest(&manifest);
+
+    match result {
+        Err(FsIOError::PathOutsideBase(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn verify_manifest_parent_directory_inside_tree() {
+    let root =
+        "./target/__test/ut/directory_test/manifest/verify_manifest_parent_directory_inside_tree";
+    write_text_file(&format!("{}/file.txt", root), "some content").unwrap();
+    let manifest = format!("{}/SHA256SUMS", root);
+    write_text_file(
+        &manifest,
+        "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56  dir/../file.txt\n",
+    )
+    .unwrap();
+
+    let report = verify_manifest(&manifest).unwrap();
+
+    assert!(report.is_valid());
+}
+
+#[test]
+#[cfg(all(feature = "hash", not(windows)))]
+fn verify_manifest_absolute_path() {
+    let root = "./target/__test/ut/directory_test/manifest/verify_manifest_absolute_path";
+    let manifest = format!("{}/SHA256SUMS", root);
+    write_text_file(
+        &manifest,
+        "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56  /etc/hostname\n",
+    )
+    .unwrap();
+
+    let result = verify_manifest(&manifest);
+
+    match result {
+        Err(FsIOError::PathOutsideBase(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn manifest_line_escaping() {
+    let digest = "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56";
+
+    let line = format_manifest_line(digest, "dir/a\\b\nc");
+    assert_eq!(line, format!("\\{}  dir/a\\\\b\\nc\n", digest));
+
+    let entry = parse_manifest_line(line.trim_end_matches('\n')).unwrap();
+    assert_eq!(entry, (digest.to_string(), "dir/a\\b\nc".to_string()));
+}
+
+#[test]
+fn move_dir_valid() {
+    let root = "./target/__test/ut/directory_test/move_dir/move_dir_valid";
+    delete(root).unwrap();
+    write_text_file(&format!("{}/source/dir/file.txt", root), "text").unwrap();
+    let target = format!("{}/dir/target", root);
+
+    move_dir(&format!("{}/source", root), &target).unwrap();
+
+    assert!(!Path::new(&format!("{}/source", root)).exists());
+    assert_eq!(read_text_file(&format!("{}/dir/file.txt", target)).unwrap(), "text");
+}
+
+#[test]
+fn move_dir_not_directory() {
+    let root = "./target/__test/ut/directory_test/move_dir/move_dir_not_directory";
+    write_text_file(&format!("{}/source", root), "text").unwrap();
+
+    let result = move_dir(&format!("{}/source", root), &format!("{}/target", root));
+
+    match result {
+        Err(FsIOError::NotDirectory(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+fn move_dir_empty_target() {
+    let root = "./target/__test/ut/directory_test/move_dir/move_dir_empty_target";
+    delete(root).unwrap();
+    write_text_file(&format!("{}/source/file.txt", root), "text").unwrap();
+    let target = format!("{}/target", root);
+    create(&target).unwrap();
+
+    move_dir(&format!("{}/source", root), &target).unwrap();
+
+    assert!(!Path::new(&format!("{}/source", root)).exists());
+    assert_eq!(read_text_file(&format!("{}/file.txt", target)).unwrap(), "text");
+}
+
+#[test]
+fn move_dir_non_empty_target() {
+    let root = "./target/__test/ut/directory_test/move_dir/move_dir_non_empty_target";
+    delete(root).unwrap();
+    write_text_file(&format!("{}/source/file.txt", root), "text").unwrap();
+    write_text_file(&format!("{}/target/other.txt", root), "other").unwrap();
+
+    let result = move_dir(&format!("{}/source", root), &format!("{}/target", root));
+
+    match result {
+        Err(FsIOError::PathAlreadyExists(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+    assert_eq!(read_text_file(&format!("{}/source/file.txt", root)).unwrap(), "text");
+    assert!(!Path::new(&format!("{}/target/file.txt", root)).exists());
+}
+
+#[test]
+fn move_directory_by_copy_valid() {
+    let root = "./target/__test/ut/directory_test/move_dir/move_directory_by_copy_valid";
+    delete(root).unwrap();
+    let source = format!("{}/source", root);
+    let target = format!("{}/target", root);
+    write_text_file(&format!("{}/dir/file.txt", source), "text").unwrap();
+    create(&format!("{}/empty", source)).unwrap();
+
+    move_directory_by_copy(Path::new(&source), Path::new(&target)).unwrap();
+
+    assert!(!Path::new(&source).exists());
+    assert_eq!(read_text_file(&format!("{}/dir/file.txt", target)).unwrap(), "text");
+    assert!(Path::new(&format!("{}/empty", target)).is_dir());
+}
+
+#[test]
+#[cfg(not(windows))]
+fn move_directory_by_copy_symlink() {
+    use std::os::unix::fs::symlink;
+
+    let root = "./target/__test/ut/directory_test/move_dir/move_directory_by_copy_symlink";
+    delete(root).unwrap();
+    let source = format!("{}/source", root);
+    let target = format!("{}/target", root);
+    write_text_file(&format!("{}/file.txt", source), "text").unwrap();
+    symlink("file.txt", &format!("{}/link", source)).unwrap();
+
+    move_directory_by_copy(Path::new(&source), Path::new(&target)).unwrap();
+
+    assert!(!Path::new(&source).exists());
+    assert_eq!(
+        read_link(&format!("{}/link", target)).unwrap(),
+        Path::new("file.txt")
+    );
+}
+
+#[test]
+fn move_directory_by_copy_conflict() {
+    let root = "./target/__test/ut/directory_test/move_dir/move_directory_by_copy_conflict";
+    delete(root).unwrap();
+    let source = format!("{}/source", root);
+    let target = format!("{}/target", root);
+    write_text_file(&format!("{}/file.txt", source), "text").unwrap();
+    write_text_file(&format!("{}/file.txt", target), "existing").unwrap();
+
+    let result = move_directory_by_copy(Path::new(&source), Path::new(&target));
+
+    match result {
+        Err(FsIOError::PathAlreadyExists(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+    assert_eq!(read_text_file(&format!("{}/file.txt", source)).unwrap(), "text");
+    assert_eq!(read_text_file(&format!("{}/file.txt", target)).unwrap(), "existing");
+}
+
+#[test]
+fn verify_directory_copy_missing_entry() {
+    let root = "./target/__test/ut/directory_test/move_dir/verify_directory_copy_missing_entry";
+    let source = format!("{}/source", root);
+    let target = format!("{}/target", root);
+    write_text_file(&format!("{}/file1.txt", source), "1").unwrap();
+    write_text_file(&format!("{}/file2.txt", source), "2").unwrap();
+    write_text_file(&format!("{}/file1.txt", target), "1").unwrap();
+
+    let result = verify_directory_copy(Path::new(&source), Path::new(&target));
+
+    assert!(result.is_err());
+}
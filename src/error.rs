@@ -23,6 +23,14 @@ pub enum FsIOError {
     PathAlreadyExists(String),
     /// Not a file error type
     NotFile(String),
+    /// Not a directory error type
+    NotDirectory(String),
+    /// Invalid path error type
+    InvalidPath(String),
+    /// Undefined environment variable error type
+    UndefinedVariable(String),
+    /// Path leading outside of its base directory error type
+    PathOutsideBase(String),
     /// IO error type
     IOError(String, Option<io::Error>),
     /// System time error type
@@ -35,6 +43,10 @@ impl Display for FsIOError {
         match self {
             Self::PathAlreadyExists(ref message) => write!(formatter, "{}", message),
             Self::NotFile(ref message) => write!(formatter, "{}", message),
+            Self::NotDirectory(ref message) => write!(formatter, "{}", message),
+            Self::InvalidPath(ref message) => write!(formatter, "{}", message),
+            Self::UndefinedVariable(ref message) => write!(formatter, "{}", message),
+            Self::PathOutsideBase(ref message) => write!(formatter, "{}", message),
             Self::IOError(ref message, ref cause) => {
                 writeln!(formatter, "{}", message)?;
                 match cause {
@@ -60,6 +72,10 @@ impl Error for FsIOError
         {
             Self::PathAlreadyExists(_)    => None,
             Self::NotFile(_)              => None,
+            Self::NotDirectory(_)         => None,
+            Self::InvalidPath(_)          => None,
+            Self::UndefinedVariable(_)    => None,
+            Self::PathOutsideBase(_)      => None,
             Self::IOError(_, err)         => err.as_ref().map(|e| e as &dyn Error),
             Self::SystemTimeError(_, err) => err.as_ref().map(|e| e as &dyn Error),
         }
@@ -12,6 +12,30 @@ fn display_error_not_file() {
     println!("{}", error);
 }
 
+#[test]
+fn display_error_not_directory() {
+    let error = FsIOError::NotDirectory("test".to_string());
+    println!("{}", error);
+}
+
+#[test]
+fn display_error_invalid_path() {
+    let error = FsIOError::InvalidPath("test".to_string());
+    println!("{}", error);
+}
+
+#[test]
+fn display_error_undefined_variable() {
+    let error = FsIOError::UndefinedVariable("test".to_string());
+    println!("{}", error);
+}
+
+#[test]
+fn display_error_path_outside_base() {
+    let error = FsIOError::PathOutsideBase("test".to_string());
+    println!("{}", error);
+}
+
 #[test]
 fn display_error_io_error() {
     let error = FsIOError::IOError("test".to_string(), None);
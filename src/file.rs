@@ -10,9 +10,31 @@ mod file_test;
 use crate::directory;
 use crate::error::FsIOError;
 use crate::path::as_path::AsPath;
-use std::fs::{read, read_to_string, remove_file, File, OpenOptions};
+use crate::path::get_parent_directory;
+use std::cmp::min;
+use std::fs::{
+    hard_link, metadata, read, read_link, read_to_string, remove_file, rename, symlink_metadata,
+    File, FileTimes, Metadata, OpenOptions,
+};
 use std::io;
-use std::io::Write;
+use std::io::{BufRead, BufReader, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "hash")]
+use md5::Md5;
+#[cfg(feature = "mmap")]
+use memmap2::Mmap;
+#[cfg(feature = "hash")]
+use sha1::Sha1;
+#[cfg(feature = "hash")]
+use sha2::{Digest, Sha256};
+#[cfg(feature = "mmap")]
+use std::ops::Deref;
 
 /// Ensures the provided path leads to an existing file.
 /// If the file does not exist, this function will create an emtpy file.
@@ -258,21 +280,122 @@ pub fn modify_file<T: AsPath + ?Sized>(
     }
 }
 
-/// Reads the requested text file and returns its content.
+/// Writes the text to the requested file path only if the current file content is different.
+/// See [write_file_if_changed](fn.write_file_if_changed.html) for more details.
 ///
 /// # Arguments
 ///
 /// * `path` - The file path
+/// * `text` - The file text content
 ///
 /// # Example
 ///
 /// ```
 /// use crate::fsio::file;
-/// use std::path::Path;
 ///
 /// fn main() {
-///     let file_path = "./target/__test/file_test/write_text_file/file.txt";
-///     let result = file::write_text_file(file_path, "some content");
+///     let file_path = "./target/__test/file_test/write_text_file_if_changed/file.txt";
+///     file::write_text_file(file_path, "some content").unwrap();
+///
+///     let written = file::write_text_file_if_changed(file_path, "some content").unwrap();
+///     assert!(!written);
+///
+///     let written = file::write_text_file_if_changed(file_path, "more content").unwrap();
+///     assert!(written);
+/// }
+/// ```
+pub fn write_text_file_if_changed<T: AsPath + ?Sized>(
+    path: &T,
+    text: &str,
+) -> Result<bool, FsIOError> {
+    write_file_if_changed(path, text.as_bytes())
+}
+
+/// Writes the raw data to the requested file path only if the current file content is different.
+/// If the content is identical, the file (including its modified time) is left untouched.
+/// The existing content is compared in chunks so it is never fully loaded to memory.
+/// Returns true if the file was written.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `data` - The file raw content
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/write_file_if_changed/file.txt";
+///
+///     let written = file::write_file_if_changed(file_path, "some content".as_bytes()).unwrap();
+///     assert!(written);
+///
+///     let written = file::write_file_if_changed(file_path, "some content".as_bytes()).unwrap();
+///     assert!(!written);
+/// }
+/// ```
+pub fn write_file_if_changed<T: AsPath + ?Sized>(path: &T, data: &[u8]) -> Result<bool, FsIOError> {
+    if is_content_equal(path.as_path(), data) {
+        Ok(false)
+    } else {
+        write_file(path, data)?;
+
+        Ok(true)
+    }
+}
+
+/// Returns true only if the file exists and its content is identical to the provided data.
+fn is_content_equal(file_path: &Path, data: &[u8]) -> bool {
+    match metadata(file_path) {
+        Ok(file_metadata) => {
+            if !file_metadata.is_file() || file_metadata.len() != data.len() as u64 {
+                return false;
+            }
+        }
+        Err(_) => return false,
+    };
+
+    let mut fd = match File::open(file_path) {
+        Ok(fd) => fd,
+        Err(_) => return false,
+    };
+
+    let mut buffer = vec![0; 64 * 1024];
+    let mut offset = 0;
+    loop {
+        match fd.read(&mut buffer) {
+            Ok(0) => return offset == data.len(),
+            Ok(size) => {
+                let end = offset + size;
+                if end > data.len() || buffer[..size] != data[offset..end] {
+                    return false;
+                }
+                offset = end;
+            }
+            Err(ref error) if error.kind() == ErrorKind::Interrupted => (),
+            Err(_) => return false,
+        }
+    }
+}
+
+/// Atomically creates/overwrites the requested file path with the provided text.
+/// See [write_file_atomic](fn.write_file_atomic.html) for more details.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `text` - The file text content
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/write_text_file_atomic/file.txt";
+///     let result = file::write_text_file_atomic(file_path, "some content");
 ///     assert!(result.is_ok());
 ///
 ///     let text = file::read_text_file(file_path).unwrap();
@@ -280,140 +403,2611 @@ pub fn modify_file<T: AsPath + ?Sized>(
 ///     assert_eq!(text, "some content");
 /// }
 /// ```
-pub fn read_text_file<T: AsPath + ?Sized>(path: &T) -> Result<String, FsIOError> {
-    let file_path = path.as_path();
-
-    match read_to_string(&file_path) {
-        Ok(content) => Ok(content),
-        Err(error) => Err(FsIOError::IOError(
-                format!("Unable to read file: {:?}", &file_path).to_string(),
-                Some(error),
-            ),
-        ),
-    }
+pub fn write_text_file_atomic<T: AsPath + ?Sized>(path: &T, text: &str) -> Result<(), FsIOError> {
+    write_file_atomic(path, text.as_bytes())
 }
 
-/// Reads the requested file and returns its content.
+/// Atomically creates/overwrites the requested file path with the provided raw data.
+/// The data is first written and synced to a temporary file in the same directory, which is
+/// then renamed over the target path, so readers will either see the old or the new content
+/// but never a partially written file.
+/// If a file exists at that path, its permissions are copied to the new file.
+/// In case the path is a symbolic link to an existing file, the link is kept and the linked
+/// file is replaced instead (a broken link is replaced by the new file).
 ///
 /// # Arguments
 ///
 /// * `path` - The file path
+/// * `data` - The file raw content
 ///
 /// # Example
 ///
 /// ```
 /// use crate::fsio::file;
-/// use std::path::Path;
 /// use std::str;
 ///
 /// fn main() {
-///     let file_path = "./target/__test/file_test/read_file/file.txt";
-///     let mut result = file::write_file(file_path, "some content".as_bytes());
+///     let file_path = "./target/__test/file_test/write_file_atomic/file.txt";
+///     let mut result = file::write_file_atomic(file_path, "some content".as_bytes());
 ///     assert!(result.is_ok());
-///     result = file::append_file(file_path, "\nmore content".as_bytes());
+///     result = file::write_file_atomic(file_path, "more content".as_bytes());
 ///     assert!(result.is_ok());
 ///
 ///     let data = file::read_file(file_path).unwrap();
 ///
-///     assert_eq!(str::from_utf8(&data).unwrap(), "some content\nmore content");
+///     assert_eq!(str::from_utf8(&data).unwrap(), "more content");
 /// }
 /// ```
-pub fn read_file<T: AsPath + ?Sized>(path: &T) -> Result<Vec<u8>, FsIOError> {
-    let file_path = path.as_path();
+pub fn write_file_atomic<T: AsPath + ?Sized>(path: &T, data: &[u8]) -> Result<(), FsIOError> {
+    directory::create_parent(path)?;
 
-    match read(&file_path) {
-        Ok(content) => Ok(content),
+    // the temporary file is renamed over the linked file, so the link itself is kept
+    let link_target_path = match symlink_metadata(path.as_path()) {
+        Ok(ref link_metadata) if link_metadata.file_type().is_symlink() => {
+            path.as_path().canonicalize().ok()
+        }
+        _ => None,
+    };
+    let file_path = link_target_path.as_deref().unwrap_or(path.as_path());
+
+    if file_path.is_dir() {
+        return Err(FsIOError::NotFile(
+                format!("Path: {:?} is not a file.", &file_path).to_string(),
+            ),
+        );
+    }
+
+    let (temp_path, mut fd) = create_sibling_temp_file(file_path)?;
+
+    let result = copy_permissions(file_path, &temp_path, &fd).and_then(|_| {
+        match fd.write_all(data).and_then(|_| fd.sync_all()) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(FsIOError::IOError(
+                    format!("Error while writing to file: {:?}", &temp_path).to_string(),
+                    Some(error),
+                ),
+            ),
+        }
+    });
+    drop(fd);
+
+    let result = result.and_then(|_| match rename(&temp_path, file_path) {
+        Ok(_) => Ok(()),
         Err(error) => Err(FsIOError::IOError(
-                format!("Unable to read file: {:?}", &file_path).to_string(),
+                format!("Unable to rename: {:?} to: {:?}", &temp_path, &file_path).to_string(),
                 Some(error),
             ),
         ),
+    });
+
+    match result {
+        Ok(_) => sync_parent_directory(file_path),
+        Err(error) => {
+            remove_file(&temp_path).unwrap_or(());
+            Err(error)
+        }
     }
 }
 
-/// Deletes the requested file.
-/// If the file does not exist, this function will return valid response.
+fn create_sibling_temp_file(file_path: &Path) -> Result<(PathBuf, File), FsIOError> {
+    let directory = match get_parent_directory(&file_path) {
+        Some(value) => PathBuf::from(value),
+        None => PathBuf::from("."),
+    };
+    let name = file_path
+        .file_name()
+        .map(|value| value.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.subsec_nanos())
+        .unwrap_or(0);
+
+    let mut attempt = 0;
+    loop {
+        let temp_path = directory.join(format!(
+            ".{}.{}.{}.{}.tmp",
+            name,
+            process::id(),
+            nanos,
+            attempt
+        ));
+
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temp_path)
+        {
+            Ok(fd) => return Ok((temp_path, fd)),
+            Err(ref error) if error.kind() == ErrorKind::AlreadyExists && attempt < 100 => {
+                attempt = attempt + 1
+            }
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to create temporary file: {:?}", &temp_path).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        }
+    }
+}
+
+fn copy_permissions(source: &Path, target: &Path, fd: &File) -> Result<(), FsIOError> {
+    match metadata(source) {
+        Ok(source_metadata) => match fd.set_permissions(source_metadata.permissions()) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(FsIOError::IOError(
+                    format!("Unable to set permissions for file: {:?}", &target).to_string(),
+                    Some(error),
+                ),
+            ),
+        },
+        Err(_) => Ok(()),
+    }
+}
+
+#[cfg(windows)]
+fn sync_parent_directory(_file_path: &Path) -> Result<(), FsIOError> {
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn sync_parent_directory(file_path: &Path) -> Result<(), FsIOError> {
+    let directory = match get_parent_directory(&file_path) {
+        Some(value) => value,
+        None => ".".to_string(),
+    };
+
+    match File::open(&directory).and_then(|fd| fd.sync_all()) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to sync directory: {:?}", &directory).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Holds the file copy options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyOptions {
+    /// True to create the target parent directories if needed (default true)
+    pub create_parent: bool,
+    /// True to copy the source permissions to the target file (default true)
+    pub preserve_permissions: bool,
+    /// True to copy the source modified and accessed times to the target file (default false)
+    pub preserve_modified_time: bool,
+}
+
+impl CopyOptions {
+    /// Returns new instance with default values.
+    pub fn new() -> CopyOptions {
+        CopyOptions {
+            create_parent: true,
+            preserve_permissions: true,
+            preserve_modified_time: false,
+        }
+    }
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        CopyOptions::new()
+    }
+}
+
+/// Copies the source file content to the target path and returns the amount of copied bytes.
+/// If a file exists at the target path, it will be overwritten.
+/// On linux, the copy first attempts a reflink (FICLONE) which shares the data blocks on
+/// copy on write file systems (btrfs, xfs), then an in kernel copy via copy_file_range and
+/// only then falls back to a buffered copy.
 ///
 /// # Arguments
 ///
-/// * `path` - The file path
+/// * `source` - The source file path
+/// * `target` - The target file path
+/// * `options` - The copy options
 ///
 /// # Example
 ///
 /// ```
 /// use crate::fsio::file;
-/// use std::path::Path;
-/// use std::str;
+/// use crate::fsio::file::CopyOptions;
 ///
 /// fn main() {
-///     let file_path = "./target/__test/file_test/delete_file/file.txt";
-///     let mut result = file::ensure_exists(file_path);
-///     assert!(result.is_ok());
-///
-///     let path = Path::new(file_path);
-///     assert!(path.exists());
+///     let source = "./target/__test/file_test/copy/source.txt";
+///     let target = "./target/__test/file_test/copy/dir/target.txt";
+///     file::write_text_file(source, "some content").unwrap();
 ///
-///     result = file::delete(file_path);
-///     assert!(result.is_ok());
+///     let size = file::copy(source, target, &CopyOptions::new()).unwrap();
+///     assert_eq!(size, 12);
 ///
-///     assert!(!path.exists());
+///     let text = file::read_text_file(target).unwrap();
+///     assert_eq!(text, "some content");
 /// }
 /// ```
-pub fn delete<T: AsPath + ?Sized>(path: &T) -> Result<(), FsIOError> {
-    let file_path = path.as_path();
+pub fn copy<S: AsPath + ?Sized, D: AsPath + ?Sized>(
+    source: &S,
+    target: &D,
+    options: &CopyOptions,
+) -> Result<u64, FsIOError> {
+    let source_path = source.as_path();
+    let target_path = target.as_path();
 
-    if file_path.exists() {
-        if file_path.is_file() {
-            match remove_file(file_path) {
-                Ok(_) => Ok(()),
-                Err(error) => Err(FsIOError::IOError(
-                        format!("Unable to delete file: {:?}", &file_path).to_string(),
-                        Some(error),
-                    ),
+    let source_metadata = match metadata(source_path) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read metadata for: {:?}", &source_path).to_string(),
+                    Some(error),
                 ),
-            }
-        } else {
-            Err(FsIOError::NotFile(
-                    format!("Path: {:?} is not a file.", &file_path).to_string(),
+            )
+        }
+    };
+    if source_metadata.is_dir() {
+        return Err(FsIOError::NotFile(
+                format!("Path: {:?} is not a file.", &source_path).to_string(),
+            ),
+        );
+    }
+    if is_same_file(source_path, &source_metadata, target_path) {
+        return Err(FsIOError::IOError(
+                format!("Source: {:?} and target: {:?} are the same file.", &source_path, &target_path)
+                    .to_string(),
+                None,
+            ),
+        );
+    }
+
+    if options.create_parent {
+        directory::create_parent(&target_path)?;
+    }
+
+    let mut source_fd = match File::open(source_path) {
+        Ok(fd) => fd,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to open file: {:?}", &source_path).to_string(),
+                    Some(error),
                 ),
             )
         }
-    } else {
+    };
+    let mut target_fd = match File::create(target_path) {
+        Ok(fd) => fd,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to create/open file: {:?} for writing.", &target_path)
+                        .to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    let size = match copy_content(&mut source_fd, &mut target_fd, source_metadata.len()) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to copy: {:?} to: {:?}", &source_path, &target_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    if options.preserve_permissions {
+        if let Err(error) = target_fd.set_permissions(source_metadata.permissions()) {
+            return Err(FsIOError::IOError(
+                    format!("Unable to set permissions for: {:?}", &target_path).to_string(),
+                    Some(error),
+                ),
+            );
+        }
+    }
+
+    if options.preserve_modified_time {
+        let mut times = FileTimes::new();
+        if let Ok(modified) = source_metadata.modified() {
+            times = times.set_modified(modified);
+        }
+        if let Ok(accessed) = source_metadata.accessed() {
+            times = times.set_accessed(accessed);
+        }
+
+        if let Err(error) = target_fd.set_times(times) {
+            return Err(FsIOError::IOError(
+                    format!("Unable to set modified time for: {:?}", &target_path).to_string(),
+                    Some(error),
+                ),
+            );
+        }
+    }
+
+    Ok(size)
+}
+
+#[cfg(windows)]
+fn is_same_file(source_path: &Path, _source_metadata: &Metadata, target_path: &Path) -> bool {
+    match (source_path.canonicalize(), target_path.canonicalize()) {
+        (Ok(source_canonical), Ok(target_canonical)) => source_canonical == target_canonical,
+        _ => false,
+    }
+}
+
+#[cfg(not(windows))]
+fn is_same_file(_source_path: &Path, source_metadata: &Metadata, target_path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match metadata(target_path) {
+        Ok(target_metadata) => {
+            source_metadata.dev() == target_metadata.dev()
+                && source_metadata.ino() == target_metadata.ino()
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn copy_content(source: &mut File, target: &mut File, _length: u64) -> io::Result<u64> {
+    io::copy(source, target)
+}
+
+#[cfg(target_os = "linux")]
+fn copy_content(source: &mut File, target: &mut File, length: u64) -> io::Result<u64> {
+    if length > 0 && clone_file(source, target).is_ok() {
+        return Ok(length);
+    }
+
+    match copy_file_range(source, target)? {
+        Some(size) => Ok(size),
+        None => io::copy(source, target),
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[allow(unsafe_code)]
+fn clone_file(source: &File, target: &File) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    // both descriptors are owned by open files for the duration of the call
+    let result = unsafe { libc::ioctl(target.as_raw_fd(), libc::FICLONE, source.as_raw_fd()) };
+    if result == 0 {
         Ok(())
+    } else {
+        Err(io::Error::last_os_error())
     }
 }
 
-/// Deletes the requested file.
-/// If the file does not exist, this function will return true.
+/// Copies the content using copy_file_range from the current file offsets.
+/// Returns none in case nothing was copied so the caller can fall back to a buffered copy,
+/// for example if the file systems do not support it or for special files reporting
+/// no content.
+#[cfg(target_os = "linux")]
+#[allow(unsafe_code)]
+fn copy_file_range(source: &File, target: &File) -> io::Result<Option<u64>> {
+    use std::os::unix::io::AsRawFd;
+    use std::ptr;
+
+    let mut copied = 0;
+    loop {
+        // null offsets make the kernel use and update the descriptors file offsets
+        let result = unsafe {
+            libc::copy_file_range(
+                source.as_raw_fd(),
+                ptr::null_mut(),
+                target.as_raw_fd(),
+                ptr::null_mut(),
+                1024 * 1024 * 1024,
+                0,
+            )
+        };
+
+        if result > 0 {
+            copied += result as u64;
+        } else if result == 0 {
+            return Ok(if copied == 0 { None } else { Some(copied) });
+        } else {
+            let error = io::Error::last_os_error();
+            match error.raw_os_error() {
+                Some(libc::EINTR) => (),
+                Some(libc::ENOSYS) | Some(libc::EXDEV) | Some(libc::EINVAL)
+                | Some(libc::EOPNOTSUPP) | Some(libc::EPERM)
+                    if copied == 0 =>
+                {
+                    return Ok(None)
+                }
+                _ => return Err(error),
+            }
+        }
+    }
+}
+
+/// Moves the source file to the target path.
+/// The file is renamed if possible and in case the target is located on a different
+/// file system, the file is copied, verified against the source content and only then
+/// the source is deleted.
+/// The target parent directories are created if needed and if a file exists at the target
+/// path, it will be overwritten.
+/// Symbolic links are moved as is, and not the file they point to.
 ///
 /// # Arguments
 ///
-/// * `path` - The file path
+/// * `source` - The source file path
+/// * `target` - The target file path
 ///
 /// # Example
 ///
 /// ```
 /// use crate::fsio::file;
 /// use std::path::Path;
-/// use std::str;
 ///
 /// fn main() {
-///     let file_path = "./target/__test/file_test/delete_file/file.txt";
-///     let result = file::ensure_exists(file_path);
-///     assert!(result.is_ok());
-///
-///     let path = Path::new(file_path);
-///     assert!(path.exists());
+///     let source = "./target/__test/file_test/move_file/source.txt";
+///     let target = "./target/__test/file_test/move_file/dir/target.txt";
+///     file::write_text_file(source, "some content").unwrap();
 ///
-///     let deleted = file::delete_ignore_error(file_path);
-///     assert!(deleted);
+///     file::move_file(source, target).unwrap();
 ///
-///     assert!(!path.exists());
+///     assert!(!Path::new(source).exists());
+///     assert_eq!(file::read_text_file(target).unwrap(), "some content");
 /// }
 /// ```
-pub fn delete_ignore_error<T: AsPath + ?Sized>(path: &T) -> bool {
-    match delete(path) {
-        Ok(_) => true,
-        Err(_) => false,
-    }
+pub fn move_file<S: AsPath + ?Sized, D: AsPath + ?Sized>(
+    source: &S,
+    target: &D,
+) -> Result<(), FsIOError> {
+    let source_path = source.as_path();
+    let target_path = target.as_path();
+
+    let source_metadata = match symlink_metadata(source_path) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read metadata for: {:?}", &source_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+    if source_metadata.is_dir() {
+        return Err(FsIOError::NotFile(
+                format!("Path: {:?} is not a file.", &source_path).to_string(),
+            ),
+        );
+    }
+
+    directory::create_parent(&target_path)?;
+
+    match rename(source_path, target_path) {
+        Ok(_) => Ok(()),
+        Err(ref error) if is_cross_device_error(error) => {
+            if source_metadata.file_type().is_symlink() {
+                move_symlink_by_copy(source_path, target_path)
+            } else {
+                move_file_by_copy(source_path, target_path)
+            }
+        }
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to move: {:?} to: {:?}", &source_path, &target_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Returns true if the error was returned for a rename across file systems.
+#[cfg(unix)]
+pub(crate) fn is_cross_device_error(error: &io::Error) -> bool {
+    error.raw_os_error() == Some(libc::EXDEV)
+}
+
+#[cfg(windows)]
+pub(crate) fn is_cross_device_error(error: &io::Error) -> bool {
+    use windows_sys::Win32::Foundation::ERROR_NOT_SAME_DEVICE;
+
+    error.raw_os_error() == Some(ERROR_NOT_SAME_DEVICE as i32)
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn is_cross_device_error(_error: &io::Error) -> bool {
+    false
+}
+
+fn move_file_by_copy(source_path: &Path, target_path: &Path) -> Result<(), FsIOError> {
+    let mut options = CopyOptions::new();
+    options.preserve_modified_time = true;
+    copy(&source_path, &target_path, &options)?;
+
+    if !is_same_content(source_path, target_path)? {
+        remove_file(target_path).unwrap_or(());
+
+        return Err(FsIOError::IOError(
+                format!("Copied file: {:?} does not match source: {:?}", &target_path, &source_path)
+                    .to_string(),
+                None,
+            ),
+        );
+    }
+
+    match remove_file(source_path) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to delete file: {:?}", &source_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Recreates the symbolic link at the target path and deletes the source link.
+fn move_symlink_by_copy(source_path: &Path, target_path: &Path) -> Result<(), FsIOError> {
+    let link = match read_link(source_path) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read symbolic link: {:?}", &source_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    // existing target files are overwritten, same as when renaming
+    if let Ok(target_metadata) = symlink_metadata(target_path) {
+        if !target_metadata.is_dir() {
+            if let Err(error) = remove_file(target_path) {
+                return Err(FsIOError::IOError(
+                        format!("Unable to delete file: {:?}", &target_path).to_string(),
+                        Some(error),
+                    ),
+                );
+            }
+        }
+    }
+
+    if let Err(error) = directory::create_symlink(source_path, &link, target_path) {
+        return Err(FsIOError::IOError(
+                format!("Unable to create symbolic link: {:?}", &target_path).to_string(),
+                Some(error),
+            ),
+        );
+    }
+
+    match remove_file(source_path) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to delete file: {:?}", &source_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Returns true if both files have the exact same content.
+/// The files are compared in chunks so they are never fully loaded to memory.
+pub(crate) fn is_same_content(first_path: &Path, second_path: &Path) -> Result<bool, FsIOError> {
+    let open_file = |file_path: &Path| match File::open(file_path) {
+        Ok(fd) => Ok(fd),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to read file: {:?}", &file_path).to_string(),
+                Some(error),
+            ),
+        ),
+    };
+    let mut first_fd = open_file(first_path)?;
+    let mut second_fd = open_file(second_path)?;
+
+    let mut first_buffer = vec![0; 64 * 1024];
+    let mut second_buffer = vec![0; 64 * 1024];
+    loop {
+        let first_size = read_chunk(&mut first_fd, &mut first_buffer, first_path)?;
+        let second_size = read_chunk(&mut second_fd, &mut second_buffer, second_path)?;
+
+        if first_buffer[..first_size] != second_buffer[..second_size] {
+            return Ok(false);
+        } else if first_size == 0 {
+            return Ok(true);
+        }
+    }
+}
+
+/// Fills the buffer unless the end of the file is reached and returns the read size.
+fn read_chunk(fd: &mut File, buffer: &mut [u8], file_path: &Path) -> Result<usize, FsIOError> {
+    let mut size = 0;
+    while size < buffer.len() {
+        match fd.read(&mut buffer[size..]) {
+            Ok(0) => break,
+            Ok(read_size) => size += read_size,
+            Err(ref error) if error.kind() == ErrorKind::Interrupted => (),
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read file: {:?}", &file_path).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        }
+    }
+
+    Ok(size)
+}
+
+/// Reads the requested text file and returns its content.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use std::path::Path;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/write_text_file/file.txt";
+///     let result = file::write_text_file(file_path, "some content");
+///     assert!(result.is_ok());
+///
+///     let text = file::read_text_file(file_path).unwrap();
+///
+///     assert_eq!(text, "some content");
+/// }
+/// ```
+pub fn read_text_file<T: AsPath + ?Sized>(path: &T) -> Result<String, FsIOError> {
+    let file_path = path.as_path();
+
+    match read_to_string(&file_path) {
+        Ok(content) => Ok(content),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to read file: {:?}", &file_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Holds the line reading options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadLinesOptions {
+    /// True to keep the line terminator at the end of every line (default false)
+    pub keep_line_terminator: bool,
+    /// True to treat \r\n as a single line terminator (default true).
+    /// Only relevant when the line terminator is not kept.
+    pub crlf: bool,
+}
+
+impl ReadLinesOptions {
+    /// Returns new instance with default values.
+    pub fn new() -> ReadLinesOptions {
+        ReadLinesOptions {
+            keep_line_terminator: false,
+            crlf: true,
+        }
+    }
+}
+
+impl Default for ReadLinesOptions {
+    fn default() -> Self {
+        ReadLinesOptions::new()
+    }
+}
+
+/// Iterator over the lines of a text file.
+/// Created via the [read_lines](fn.read_lines.html) and
+/// [read_lines_with_options](fn.read_lines_with_options.html) functions.
+#[derive(Debug)]
+pub struct ReadLines {
+    path: PathBuf,
+    reader: BufReader<File>,
+    options: ReadLinesOptions,
+    done: bool,
+}
+
+impl Iterator for ReadLines {
+    type Item = Result<String, FsIOError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut buffer = vec![];
+        match self.reader.read_until(b'\n', &mut buffer) {
+            Ok(0) => {
+                self.done = true;
+                None
+            }
+            Ok(_) => {
+                if !self.options.keep_line_terminator && buffer.ends_with(b"\n") {
+                    buffer.pop();
+                    if self.options.crlf && buffer.ends_with(b"\r") {
+                        buffer.pop();
+                    }
+                }
+
+                // only the invalid line fails, the next lines can still be read
+                match String::from_utf8(buffer) {
+                    Ok(line) => Some(Ok(line)),
+                    Err(error) => Some(Err(FsIOError::IOError(
+                            format!("Unable to read file: {:?}", &self.path).to_string(),
+                            Some(io::Error::new(ErrorKind::InvalidData, error)),
+                        ),
+                    )),
+                }
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(FsIOError::IOError(
+                        format!("Unable to read file: {:?}", &self.path).to_string(),
+                        Some(error),
+                    ),
+                ))
+            }
+        }
+    }
+}
+
+/// Opens the requested text file and returns an iterator over its lines.
+/// The file is read in a buffered manner so it is never fully loaded to memory.
+/// Line terminators (both \n and \r\n) are stripped from the returned lines.
+/// Lines which are not valid UTF-8 are returned as errors and the iteration continues with
+/// the next line.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/read_lines/file.txt";
+///     let result = file::write_text_file(file_path, "line 1\nline 2\r\nline 3");
+///     assert!(result.is_ok());
+///
+///     let lines: Vec<String> = file::read_lines(file_path)
+///         .unwrap()
+///         .map(|line| line.unwrap())
+///         .collect();
+///
+///     assert_eq!(lines, vec!["line 1", "line 2", "line 3"]);
+/// }
+/// ```
+pub fn read_lines<T: AsPath + ?Sized>(path: &T) -> Result<ReadLines, FsIOError> {
+    read_lines_with_options(path, &ReadLinesOptions::new())
+}
+
+/// Opens the requested text file and returns an iterator over its lines based on the
+/// provided options.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `options` - The line reading options
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use crate::fsio::file::ReadLinesOptions;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/read_lines_with_options/file.txt";
+///     let result = file::write_text_file(file_path, "line 1\nline 2\r\nline 3");
+///     assert!(result.is_ok());
+///
+///     let mut options = ReadLinesOptions::new();
+///     options.keep_line_terminator = true;
+///     let lines: Vec<String> = file::read_lines_with_options(file_path, &options)
+///         .unwrap()
+///         .map(|line| line.unwrap())
+///         .collect();
+///
+///     assert_eq!(lines, vec!["line 1\n", "line 2\r\n", "line 3"]);
+/// }
+/// ```
+pub fn read_lines_with_options<T: AsPath + ?Sized>(
+    path: &T,
+    options: &ReadLinesOptions,
+) -> Result<ReadLines, FsIOError> {
+    let file_path = path.as_path();
+
+    match File::open(&file_path) {
+        Ok(fd) => Ok(ReadLines {
+            path: file_path.to_path_buf(),
+            reader: BufReader::new(fd),
+            options: *options,
+            done: false,
+        }),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to read file: {:?}", &file_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Reads the requested file and returns its content.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use std::path::Path;
+/// use std::str;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/read_file/file.txt";
+///     let mut result = file::write_file(file_path, "some content".as_bytes());
+///     assert!(result.is_ok());
+///     result = file::append_file(file_path, "\nmore content".as_bytes());
+///     assert!(result.is_ok());
+///
+///     let data = file::read_file(file_path).unwrap();
+///
+///     assert_eq!(str::from_utf8(&data).unwrap(), "some content\nmore content");
+/// }
+/// ```
+pub fn read_file<T: AsPath + ?Sized>(path: &T) -> Result<Vec<u8>, FsIOError> {
+    let file_path = path.as_path();
+
+    match read(&file_path) {
+        Ok(content) => Ok(content),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to read file: {:?}", &file_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Reads up to the requested amount of bytes starting at the provided offset.
+/// Less bytes are returned in case the end of the file is reached.
+/// The data is read using positional I/O so it does not depend on (or modify) the position
+/// of other handles.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `offset` - The offset from the start of the file
+/// * `length` - The maximum amount of bytes to read
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/read_range/file.txt";
+///     file::write_text_file(file_path, "some content").unwrap();
+///
+///     let data = file::read_range(file_path, 5, 3).unwrap();
+///
+///     assert_eq!(data, "con".as_bytes());
+/// }
+/// ```
+pub fn read_range<T: AsPath + ?Sized>(
+    path: &T,
+    offset: u64,
+    length: usize,
+) -> Result<Vec<u8>, FsIOError> {
+    let file_path = path.as_path();
+    let fd = open_for_positional_io(file_path, false)?;
+
+    read_range_from_file(&fd, file_path, offset, length)
+}
+
+/// Writes the data at the provided offset of an existing file, without truncating it.
+/// Writing beyond the end of the file extends it.
+/// The data is written using positional I/O so it does not depend on (or modify) the
+/// position of other handles.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `offset` - The offset from the start of the file
+/// * `data` - The data to write
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/write_at/file.txt";
+///     file::write_text_file(file_path, "some content").unwrap();
+///
+///     file::write_at(file_path, 5, "CON".as_bytes()).unwrap();
+///
+///     assert_eq!(file::read_text_file(file_path).unwrap(), "some CONtent");
+/// }
+/// ```
+pub fn write_at<T: AsPath + ?Sized>(path: &T, offset: u64, data: &[u8]) -> Result<(), FsIOError> {
+    let file_path = path.as_path();
+    let fd = open_for_positional_io(file_path, true)?;
+
+    let mut written = 0;
+    while written < data.len() {
+        let position = get_position(file_path, offset, written)?;
+        match positional_write(&fd, &data[written..], position) {
+            Ok(0) => {
+                return Err(FsIOError::IOError(
+                        format!("Error while writing to file: {:?}", &file_path).to_string(),
+                        Some(io::Error::from(ErrorKind::WriteZero)),
+                    ),
+                )
+            }
+            Ok(size) => written += size,
+            Err(ref error) if error.kind() == ErrorKind::Interrupted => (),
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Error while writing to file: {:?}", &file_path).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        }
+    }
+
+    match fd.sync_all() {
+        Ok(_) => Ok(()),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Error finish up writing to file: {:?}", &file_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Reads the last requested amount of bytes of the file (or the whole file if it is smaller).
+/// The file size is taken from its metadata, so only regular files are supported and a NotFile
+/// error is returned for special files (such as fifo) which do not report their size.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `length` - The maximum amount of bytes to read
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/read_tail/file.txt";
+///     file::write_text_file(file_path, "some content").unwrap();
+///
+///     let data = file::read_tail(file_path, 7).unwrap();
+///
+///     assert_eq!(data, "content".as_bytes());
+/// }
+/// ```
+pub fn read_tail<T: AsPath + ?Sized>(path: &T, length: usize) -> Result<Vec<u8>, FsIOError> {
+    let file_path = path.as_path();
+
+    // checked before opening since opening a fifo blocks until the other side is opened
+    if let Ok(file_metadata) = metadata(file_path) {
+        if !file_metadata.is_file() {
+            return Err(FsIOError::NotFile(
+                    format!("Path: {:?} is not a regular file.", &file_path).to_string(),
+                ),
+            );
+        }
+    }
+
+    let fd = open_for_positional_io(file_path, false)?;
+
+    let file_length = match fd.metadata() {
+        Ok(file_metadata) => file_metadata.len(),
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read metadata for: {:?}", &file_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+    let offset = file_length.saturating_sub(length as u64);
+
+    read_range_from_file(&fd, file_path, offset, length)
+}
+
+fn open_for_positional_io(file_path: &Path, write: bool) -> Result<File, FsIOError> {
+    if file_path.is_dir() {
+        return Err(FsIOError::NotFile(
+                format!("Path: {:?} is not a file.", &file_path).to_string(),
+            ),
+        );
+    }
+
+    match OpenOptions::new().read(true).write(write).open(file_path) {
+        Ok(fd) => Ok(fd),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to open file: {:?}", &file_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+fn read_range_from_file(
+    fd: &File,
+    file_path: &Path,
+    offset: u64,
+    length: usize,
+) -> Result<Vec<u8>, FsIOError> {
+    // read in chunks to avoid allocating the full requested length for ranges beyond the
+    // end of the file, since special files do not report their size
+    let mut buffer = vec![];
+    let mut chunk = vec![0; min(length, 64 * 1024)];
+    while buffer.len() < length {
+        let chunk_size = min(chunk.len(), length - buffer.len());
+        let position = get_position(file_path, offset, buffer.len())?;
+        match positional_read(fd, &mut chunk[..chunk_size], position) {
+            Ok(0) => break,
+            Ok(size) => buffer.extend_from_slice(&chunk[..size]),
+            Err(ref error) if error.kind() == ErrorKind::Interrupted => (),
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read file: {:?}", &file_path).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        }
+    }
+
+    Ok(buffer)
+}
+
+/// Returns the file position of the already processed bytes, failing instead of overflowing.
+fn get_position(file_path: &Path, offset: u64, processed: usize) -> Result<u64, FsIOError> {
+    match offset.checked_add(processed as u64) {
+        Some(position) => Ok(position),
+        None => Err(FsIOError::IOError(
+                format!("Offset: {} is out of range for file: {:?}", offset, &file_path)
+                    .to_string(),
+                Some(io::Error::from(ErrorKind::InvalidInput)),
+            ),
+        ),
+    }
+}
+
+#[cfg(windows)]
+fn positional_read(fd: &File, buffer: &mut [u8], offset: u64) -> io::Result<usize> {
+    use std::os::windows::fs::FileExt;
+
+    fd.seek_read(buffer, offset)
+}
+
+#[cfg(not(windows))]
+fn positional_read(fd: &File, buffer: &mut [u8], offset: u64) -> io::Result<usize> {
+    use std::os::unix::fs::FileExt;
+
+    fd.read_at(buffer, offset)
+}
+
+#[cfg(windows)]
+fn positional_write(fd: &File, data: &[u8], offset: u64) -> io::Result<usize> {
+    use std::os::windows::fs::FileExt;
+
+    fd.seek_write(data, offset)
+}
+
+#[cfg(not(windows))]
+fn positional_write(fd: &File, data: &[u8], offset: u64) -> io::Result<usize> {
+    use std::os::unix::fs::FileExt;
+
+    fd.write_at(data, offset)
+}
+
+/// Read only file content returned by [map_file](fn.map_file.html).
+/// Derefs to the file bytes, which are either memory mapped or read to memory.
+#[cfg(feature = "mmap")]
+#[derive(Debug)]
+pub struct MappedFile {
+    content: MappedContent,
+}
+
+#[cfg(feature = "mmap")]
+#[derive(Debug)]
+enum MappedContent {
+    Mapped(Mmap),
+    Buffer(Vec<u8>),
+}
+
+#[cfg(feature = "mmap")]
+impl MappedFile {
+    /// Returns true if the content is memory mapped and false if it was read to memory.
+    pub fn is_mapped(&self) -> bool {
+        match self.content {
+            MappedContent::Mapped(_) => true,
+            MappedContent::Buffer(_) => false,
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl Deref for MappedFile {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self.content {
+            MappedContent::Mapped(ref mapping) => mapping,
+            MappedContent::Buffer(ref buffer) => buffer,
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl AsRef<[u8]> for MappedFile {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+/// Returns a read only memory mapping of the requested file content.
+/// Empty files and special files (for example devices, pipes and files under /proc), which
+/// can not be mapped, are read to memory via [read_file](fn.read_file.html) instead.
+///
+/// # Safety
+///
+/// The file must not be modified or truncated (by this or any other process) while the
+/// returned content is alive.
+/// Modifications change the content behind the returned slice and reading a truncated
+/// region may crash the process (SIGBUS), both are undefined behavior.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Feature
+///
+/// This function requires that the **mmap** feature will be used.
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/map_file/file.txt";
+///     file::write_text_file(file_path, "some content").unwrap();
+///
+///     // the file is not modified while mapped
+///     let content = unsafe { file::map_file(file_path) }.unwrap();
+///
+///     assert_eq!(&content[..], "some content".as_bytes());
+/// }
+/// ```
+#[cfg(feature = "mmap")]
+#[allow(unsafe_code)]
+pub unsafe fn map_file<T: AsPath + ?Sized>(path: &T) -> Result<MappedFile, FsIOError> {
+    let file_path = path.as_path();
+
+    let fd = match File::open(file_path) {
+        Ok(fd) => fd,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read file: {:?}", &file_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    let mappable = match fd.metadata() {
+        Ok(file_metadata) => file_metadata.is_file() && file_metadata.len() > 0,
+        Err(_) => false,
+    };
+
+    // files which can not be mapped are read instead
+    let mapping = if mappable {
+        Mmap::map(&fd).ok()
+    } else {
+        None
+    };
+    let content = match mapping {
+        Some(mapping) => MappedContent::Mapped(mapping),
+        None => MappedContent::Buffer(read_file(&file_path)?),
+    };
+
+    Ok(MappedFile { content })
+}
+
+/// Defines the supported content hashing algorithms.
+#[cfg(feature = "hash")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// SHA-256
+    Sha256,
+    /// SHA-1
+    Sha1,
+    /// MD5
+    Md5,
+    /// CRC32 (IEEE), a fast non cryptographic checksum
+    Crc32,
+}
+
+#[cfg(feature = "hash")]
+pub(crate) enum Hasher {
+    Sha256(Sha256),
+    Sha1(Sha1),
+    Md5(Md5),
+    Crc32(crc32fast::Hasher),
+}
+
+#[cfg(feature = "hash")]
+impl Hasher {
+    pub(crate) fn new(algorithm: HashAlgorithm) -> Hasher {
+        match algorithm {
+            HashAlgorithm::Sha256 => Hasher::Sha256(Sha256::new()),
+            HashAlgorithm::Sha1 => Hasher::Sha1(Sha1::new()),
+            HashAlgorithm::Md5 => Hasher::Md5(Md5::new()),
+            HashAlgorithm::Crc32 => Hasher::Crc32(crc32fast::Hasher::new()),
+        }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        match self {
+            Hasher::Sha256(ref mut hasher) => hasher.update(data),
+            Hasher::Sha1(ref mut hasher) => hasher.update(data),
+            Hasher::Md5(ref mut hasher) => hasher.update(data),
+            Hasher::Crc32(ref mut hasher) => hasher.update(data),
+        }
+    }
+
+    /// Returns the digest as lower case hex string.
+    pub(crate) fn finish(self) -> String {
+        let digest = match self {
+            Hasher::Sha256(hasher) => hasher.finalize().to_vec(),
+            Hasher::Sha1(hasher) => hasher.finalize().to_vec(),
+            Hasher::Md5(hasher) => hasher.finalize().to_vec(),
+            Hasher::Crc32(hasher) => hasher.finalize().to_be_bytes().to_vec(),
+        };
+
+        digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    pub(crate) fn update_from_file(&mut self, file_path: &Path) -> Result<(), FsIOError> {
+        let mut fd = match File::open(file_path) {
+            Ok(fd) => fd,
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read file: {:?}", &file_path).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        };
+
+        let mut buffer = vec![0; 64 * 1024];
+        loop {
+            match fd.read(&mut buffer) {
+                Ok(0) => return Ok(()),
+                Ok(size) => self.update(&buffer[..size]),
+                Err(ref error) if error.kind() == ErrorKind::Interrupted => (),
+                Err(error) => {
+                    return Err(FsIOError::IOError(
+                            format!("Unable to read file: {:?}", &file_path).to_string(),
+                            Some(error),
+                        ),
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Returns the hash of the requested file content as lower case hex string.
+/// The file is read in chunks so it is never fully loaded to memory.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `algorithm` - The hash algorithm
+///
+/// # Feature
+///
+/// This function requires that the **hash** feature will be used.
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use crate::fsio::file::HashAlgorithm;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/hash/file.txt";
+///     file::write_text_file(file_path, "some content").unwrap();
+///
+///     let digest = file::hash(file_path, HashAlgorithm::Sha256).unwrap();
+///
+///     assert_eq!(digest, "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56");
+/// }
+/// ```
+#[cfg(feature = "hash")]
+pub fn hash<T: AsPath + ?Sized>(path: &T, algorithm: HashAlgorithm) -> Result<String, FsIOError> {
+    let mut hasher = Hasher::new(algorithm);
+
+    hasher.update_from_file(path.as_path())?;
+
+    Ok(hasher.finish())
+}
+
+/// Deletes the requested file.
+/// If the file does not exist, this function will return valid response.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use std::path::Path;
+/// use std::str;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/delete_file/file.txt";
+///     let mut result = file::ensure_exists(file_path);
+///     assert!(result.is_ok());
+///
+///     let path = Path::new(file_path);
+///     assert!(path.exists());
+///
+///     result = file::delete(file_path);
+///     assert!(result.is_ok());
+///
+///     assert!(!path.exists());
+/// }
+/// ```
+pub fn delete<T: AsPath + ?Sized>(path: &T) -> Result<(), FsIOError> {
+    let file_path = path.as_path();
+
+    if file_path.exists() {
+        if file_path.is_file() {
+            match remove_file(file_path) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(FsIOError::IOError(
+                        format!("Unable to delete file: {:?}", &file_path).to_string(),
+                        Some(error),
+                    ),
+                ),
+            }
+        } else {
+            Err(FsIOError::NotFile(
+                    format!("Path: {:?} is not a file.", &file_path).to_string(),
+                ),
+            )
+        }
+    } else {
+        Ok(())
+    }
+}
+
+/// Deletes the requested file.
+/// If the file does not exist, this function will return true.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use std::path::Path;
+/// use std::str;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/delete_file/file.txt";
+///     let result = file::ensure_exists(file_path);
+///     assert!(result.is_ok());
+///
+///     let path = Path::new(file_path);
+///     assert!(path.exists());
+///
+///     let deleted = file::delete_ignore_error(file_path);
+///     assert!(deleted);
+///
+///     assert!(!path.exists());
+/// }
+/// ```
+pub fn delete_ignore_error<T: AsPath + ?Sized>(path: &T) -> bool {
+    match delete(path) {
+        Ok(_) => true,
+        Err(_) => false,
+    }
+}
+
+/// Holds an advisory lock on a file which is released when dropped.
+/// Created via the [lock_exclusive](fn.lock_exclusive.html),
+/// [lock_shared](fn.lock_shared.html) and related functions.
+#[derive(Debug)]
+pub struct FileLock {
+    path: PathBuf,
+    file: File,
+}
+
+impl FileLock {
+    /// Returns the locked file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the locked file handle.
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        unlock_file_handle(&self.file).unwrap_or(());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LockMode {
+    Exclusive,
+    Shared,
+}
+
+/// Blocks until an exclusive advisory lock is acquired on the requested file and returns a
+/// guard which releases the lock when dropped.
+/// The file (and if needed the parent directories) is created if missing.
+/// Advisory locks are only honored by processes which lock the same file, and since the lock
+/// is bound to the file itself, a dedicated lock file should be used to guard files which are
+/// replaced (for example via [write_file_atomic](fn.write_file_atomic.html)).
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let lock_path = "./target/__test/file_test/lock_exclusive/state.lock";
+///     let lock = file::lock_exclusive(lock_path).unwrap();
+///
+///     // the lock is held so no other lock can be acquired
+///     assert!(file::try_lock_shared(lock_path).unwrap().is_none());
+///
+///     drop(lock);
+///     assert!(file::try_lock_shared(lock_path).unwrap().is_some());
+/// }
+/// ```
+pub fn lock_exclusive<T: AsPath + ?Sized>(path: &T) -> Result<FileLock, FsIOError> {
+    lock(path.as_path(), LockMode::Exclusive)
+}
+
+/// Blocks until a shared advisory lock is acquired on the requested file and returns a
+/// guard which releases the lock when dropped.
+/// Multiple shared locks can be held at the same time but not together with an exclusive lock.
+/// See [lock_exclusive](fn.lock_exclusive.html) for more details.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let lock_path = "./target/__test/file_test/lock_shared/state.lock";
+///     let _lock1 = file::lock_shared(lock_path).unwrap();
+///     let _lock2 = file::lock_shared(lock_path).unwrap();
+///
+///     assert!(file::try_lock_exclusive(lock_path).unwrap().is_none());
+/// }
+/// ```
+pub fn lock_shared<T: AsPath + ?Sized>(path: &T) -> Result<FileLock, FsIOError> {
+    lock(path.as_path(), LockMode::Shared)
+}
+
+/// Attempts to acquire an exclusive advisory lock on the requested file without blocking.
+/// Returns none if the lock is held by someone else.
+/// See [lock_exclusive](fn.lock_exclusive.html) for more details.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let lock_path = "./target/__test/file_test/try_lock_exclusive/state.lock";
+///     let lock = file::try_lock_exclusive(lock_path).unwrap();
+///     assert!(lock.is_some());
+///
+///     assert!(file::try_lock_exclusive(lock_path).unwrap().is_none());
+/// }
+/// ```
+pub fn try_lock_exclusive<T: AsPath + ?Sized>(path: &T) -> Result<Option<FileLock>, FsIOError> {
+    try_lock(path.as_path(), LockMode::Exclusive)
+}
+
+/// Attempts to acquire a shared advisory lock on the requested file without blocking.
+/// Returns none if an exclusive lock is held by someone else.
+/// See [lock_shared](fn.lock_shared.html) for more details.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+///
+/// fn main() {
+///     let lock_path = "./target/__test/file_test/try_lock_shared/state.lock";
+///     let lock1 = file::try_lock_shared(lock_path).unwrap();
+///     let lock2 = file::try_lock_shared(lock_path).unwrap();
+///
+///     assert!(lock1.is_some());
+///     assert!(lock2.is_some());
+/// }
+/// ```
+pub fn try_lock_shared<T: AsPath + ?Sized>(path: &T) -> Result<Option<FileLock>, FsIOError> {
+    try_lock(path.as_path(), LockMode::Shared)
+}
+
+/// Attempts to acquire an exclusive advisory lock on the requested file, waiting up to the
+/// provided timeout.
+/// Returns none if the lock could not be acquired in time.
+/// See [lock_exclusive](fn.lock_exclusive.html) for more details.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `timeout` - The maximum time to wait for the lock
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use std::time::Duration;
+///
+/// fn main() {
+///     let lock_path = "./target/__test/file_test/lock_exclusive_timeout/state.lock";
+///     let lock = file::lock_exclusive_timeout(lock_path, Duration::from_millis(100)).unwrap();
+///     assert!(lock.is_some());
+///
+///     let lock2 = file::lock_exclusive_timeout(lock_path, Duration::from_millis(100)).unwrap();
+///     assert!(lock2.is_none());
+/// }
+/// ```
+pub fn lock_exclusive_timeout<T: AsPath + ?Sized>(
+    path: &T,
+    timeout: Duration,
+) -> Result<Option<FileLock>, FsIOError> {
+    lock_timeout(path.as_path(), LockMode::Exclusive, timeout)
+}
+
+/// Attempts to acquire a shared advisory lock on the requested file, waiting up to the
+/// provided timeout.
+/// Returns none if the lock could not be acquired in time.
+/// See [lock_shared](fn.lock_shared.html) for more details.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `timeout` - The maximum time to wait for the lock
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use std::time::Duration;
+///
+/// fn main() {
+///     let lock_path = "./target/__test/file_test/lock_shared_timeout/state.lock";
+///     let lock = file::lock_shared_timeout(lock_path, Duration::from_millis(100)).unwrap();
+///
+///     assert!(lock.is_some());
+/// }
+/// ```
+pub fn lock_shared_timeout<T: AsPath + ?Sized>(
+    path: &T,
+    timeout: Duration,
+) -> Result<Option<FileLock>, FsIOError> {
+    lock_timeout(path.as_path(), LockMode::Shared, timeout)
+}
+
+fn open_lock_file(file_path: &Path) -> Result<File, FsIOError> {
+    directory::create_parent(&file_path)?;
+
+    match OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .open(file_path)
+    {
+        Ok(fd) => Ok(fd),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to create/open file: {:?} for locking.", &file_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+fn lock(file_path: &Path, mode: LockMode) -> Result<FileLock, FsIOError> {
+    let file = open_lock_file(file_path)?;
+
+    match lock_file_handle(&file, mode, true) {
+        Ok(_) => Ok(FileLock {
+            path: file_path.to_path_buf(),
+            file,
+        }),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to lock file: {:?}", &file_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+fn try_lock(file_path: &Path, mode: LockMode) -> Result<Option<FileLock>, FsIOError> {
+    let file = open_lock_file(file_path)?;
+
+    match lock_file_handle(&file, mode, false) {
+        Ok(true) => Ok(Some(FileLock {
+            path: file_path.to_path_buf(),
+            file,
+        })),
+        Ok(false) => Ok(None),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to lock file: {:?}", &file_path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+/// Locks the whole file, returns false in case the lock is held by another handle and blocking
+/// is not requested.
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn lock_file_handle(file: &File, mode: LockMode, blocking: bool) -> io::Result<bool> {
+    use std::os::unix::io::AsRawFd;
+
+    let mut operation = match mode {
+        LockMode::Exclusive => libc::LOCK_EX,
+        LockMode::Shared => libc::LOCK_SH,
+    };
+    if !blocking {
+        operation |= libc::LOCK_NB;
+    }
+
+    loop {
+        // the file descriptor is owned by the file and stays open during the call
+        let result = unsafe { libc::flock(file.as_raw_fd(), operation) };
+        if result == 0 {
+            return Ok(true);
+        }
+
+        let error = io::Error::last_os_error();
+        match error.raw_os_error() {
+            Some(libc::EINTR) => (),
+            Some(libc::EWOULDBLOCK) if !blocking => return Ok(false),
+            _ => return Err(error),
+        }
+    }
+}
+
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn unlock_file_handle(file: &File) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    // the file descriptor is owned by the file and stays open during the call
+    let result = unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_UN) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Locks the whole file, returns false in case the lock is held by another handle and blocking
+/// is not requested.
+#[cfg(windows)]
+#[allow(unsafe_code)]
+fn lock_file_handle(file: &File, mode: LockMode, blocking: bool) -> io::Result<bool> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Foundation::ERROR_LOCK_VIOLATION;
+    use windows_sys::Win32::Storage::FileSystem::{
+        LockFileEx, LOCKFILE_EXCLUSIVE_LOCK, LOCKFILE_FAIL_IMMEDIATELY,
+    };
+    use windows_sys::Win32::System::IO::OVERLAPPED;
+
+    let mut flags = 0;
+    if mode == LockMode::Exclusive {
+        flags |= LOCKFILE_EXCLUSIVE_LOCK;
+    }
+    if !blocking {
+        flags |= LOCKFILE_FAIL_IMMEDIATELY;
+    }
+
+    // the handle is owned by the file and stays open during the call, the overlapped structure
+    // only holds the lock offset (0) and outlives the synchronous call
+    let result = unsafe {
+        let mut overlapped: OVERLAPPED = std::mem::zeroed();
+        LockFileEx(
+            file.as_raw_handle() as _,
+            flags,
+            0,
+            u32::MAX,
+            u32::MAX,
+            &mut overlapped,
+        )
+    };
+    if result != 0 {
+        return Ok(true);
+    }
+
+    let error = io::Error::last_os_error();
+    match error.raw_os_error() {
+        Some(code) if code == ERROR_LOCK_VIOLATION as i32 && !blocking => Ok(false),
+        _ => Err(error),
+    }
+}
+
+#[cfg(windows)]
+#[allow(unsafe_code)]
+fn unlock_file_handle(file: &File) -> io::Result<()> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Storage::FileSystem::UnlockFile;
+
+    // the handle is owned by the file and stays open during the call
+    let result = unsafe { UnlockFile(file.as_raw_handle() as _, 0, 0, u32::MAX, u32::MAX) };
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+fn lock_timeout(
+    file_path: &Path,
+    mode: LockMode,
+    timeout: Duration,
+) -> Result<Option<FileLock>, FsIOError> {
+    let start_time = Instant::now();
+    loop {
+        if let Some(lock) = try_lock(file_path, mode)? {
+            return Ok(Some(lock));
+        }
+
+        let elapsed = start_time.elapsed();
+        if elapsed >= timeout {
+            return Ok(None);
+        }
+
+        thread::sleep(min(timeout - elapsed, Duration::from_millis(10)));
+    }
+}
+
+/// Holds the owner information written to a [LockFile](struct.LockFile.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockFileOwner {
+    /// The owner process id
+    pub pid: u32,
+    /// The owner host name
+    pub hostname: String,
+    /// The time the lock was acquired in millies since unix epoch time
+    pub start_time: u128,
+    /// The owner process start time since boot in clock ticks (linux only)
+    pub process_start_ticks: Option<u64>,
+    /// The boot id of the system the owner process runs on (linux only)
+    pub boot_id: Option<String>,
+}
+
+impl LockFileOwner {
+    fn current() -> LockFileOwner {
+        let hostname = get_hostname();
+        let start_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or(0);
+        let (process_start_ticks, boot_id) = get_current_process_identity();
+
+        LockFileOwner {
+            pid: process::id(),
+            hostname,
+            start_time,
+            process_start_ticks,
+            boot_id,
+        }
+    }
+
+    fn parse(text: &str) -> Option<LockFileOwner> {
+        let mut pid = None;
+        let mut hostname = None;
+        let mut start_time = None;
+        let mut process_start_ticks = None;
+        let mut boot_id = None;
+        for line in text.lines() {
+            match line.split_once('=') {
+                Some(("pid", value)) => pid = value.parse().ok(),
+                Some(("hostname", value)) => hostname = Some(value.to_string()),
+                Some(("start_time", value)) => start_time = value.parse().ok(),
+                Some(("process_start_ticks", value)) => process_start_ticks = value.parse().ok(),
+                Some(("boot_id", value)) => boot_id = Some(value.to_string()),
+                _ => (),
+            }
+        }
+
+        Some(LockFileOwner {
+            pid: pid?,
+            hostname: hostname?,
+            start_time: start_time?,
+            process_start_ticks,
+            boot_id,
+        })
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = format!(
+            "pid={}\nhostname={}\nstart_time={}\n",
+            self.pid, self.hostname, self.start_time
+        );
+        if let Some(process_start_ticks) = self.process_start_ticks {
+            text.push_str(&format!("process_start_ticks={}\n", process_start_ticks));
+        }
+        if let Some(ref boot_id) = self.boot_id {
+            text.push_str(&format!("boot_id={}\n", boot_id));
+        }
+
+        text
+    }
+}
+
+/// A lock file holding the owner process information, which is removed when dropped.
+/// Used to prevent multiple processes from running the same operation concurrently.
+#[derive(Debug)]
+pub struct LockFile {
+    path: PathBuf,
+    owner: LockFileOwner,
+}
+
+impl LockFile {
+    /// Atomically creates the lock file containing the current process id, host name and
+    /// start time.
+    /// If the lock file already exists, its owner is checked and the lock is reclaimed only in
+    /// case the owner process, on the same host, is proven to no longer exist: the system was
+    /// rebooted, the process id is not running or it was reused by another process (checked
+    /// via /proc on linux, on other platforms existing locks are never considered stale).
+    /// Otherwise a PathAlreadyExists error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The lock file path
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use crate::fsio::file::LockFile;
+    /// use std::path::Path;
+    ///
+    /// fn main() {
+    ///     let lock_path = "./target/__test/file_test/lock_file/run.lock";
+    ///     let lock = LockFile::acquire(lock_path).unwrap();
+    ///
+    ///     // a second run is rejected while the lock is held
+    ///     assert!(LockFile::acquire(lock_path).is_err());
+    ///
+    ///     drop(lock);
+    ///     assert!(!Path::new(lock_path).exists());
+    /// }
+    /// ```
+    pub fn acquire<T: AsPath + ?Sized>(path: &T) -> Result<LockFile, FsIOError> {
+        let file_path = path.as_path();
+        directory::create_parent(&file_path)?;
+
+        let owner = LockFileOwner::current();
+        let text = owner.to_text();
+
+        // retry in case the existing lock is removed or reclaimed while checking it
+        for _ in 0..5 {
+            if create_lock_file(file_path, &text)? {
+                return Ok(LockFile {
+                    path: file_path.to_path_buf(),
+                    owner,
+                });
+            }
+
+            let existing_text = match read_to_string(file_path) {
+                Ok(value) => value,
+                Err(ref error) if error.kind() == ErrorKind::NotFound => continue,
+                Err(error) => {
+                    return Err(FsIOError::IOError(
+                            format!("Unable to read lock file: {:?}", &file_path).to_string(),
+                            Some(error),
+                        ),
+                    )
+                }
+            };
+
+            match LockFileOwner::parse(&existing_text) {
+                Some(existing_owner) => {
+                    if existing_owner.hostname == owner.hostname
+                        && !is_owner_alive(&existing_owner)
+                    {
+                        reclaim_lock_file(file_path, &existing_text)?;
+                    } else {
+                        return Err(FsIOError::PathAlreadyExists(
+                                format!(
+                                    "Lock file: {:?} is held by process: {} on host: {}",
+                                    &file_path, existing_owner.pid, existing_owner.hostname
+                                )
+                                .to_string(),
+                            ),
+                        );
+                    }
+                }
+                None => {
+                    return Err(FsIOError::PathAlreadyExists(
+                            format!("Lock file: {:?} exists with invalid content.", &file_path)
+                                .to_string(),
+                        ),
+                    )
+                }
+            }
+        }
+
+        Err(FsIOError::PathAlreadyExists(
+                format!("Unable to acquire lock file: {:?}", &file_path).to_string(),
+            ),
+        )
+    }
+
+    /// Reads the owner information of an existing lock file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The lock file path
+    pub fn read_owner<T: AsPath + ?Sized>(path: &T) -> Result<LockFileOwner, FsIOError> {
+        let file_path = path.as_path();
+        let text = read_text_file(&file_path)?;
+
+        match LockFileOwner::parse(&text) {
+            Some(owner) => Ok(owner),
+            None => Err(FsIOError::IOError(
+                    format!("Invalid lock file: {:?}", &file_path).to_string(),
+                    None,
+                ),
+            ),
+        }
+    }
+
+    /// Returns the lock file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the lock file owner, which is the current process.
+    pub fn owner(&self) -> &LockFileOwner {
+        &self.owner
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        // do not remove a lock file which was (wrongly) reclaimed by another process
+        if let Ok(owner) = LockFile::read_owner(&self.path) {
+            if owner == self.owner {
+                remove_file(&self.path).unwrap_or(());
+            }
+        }
+    }
+}
+
+/// Creates the lock file with the full content in a single step, by hard linking a fully
+/// written temporary file, so other processes never see a partially written lock file.
+/// Returns false if the lock file already exists.
+fn create_lock_file(file_path: &Path, text: &str) -> Result<bool, FsIOError> {
+    let (temp_path, mut fd) = create_sibling_temp_file(file_path)?;
+
+    let result = match fd.write_all(text.as_bytes()).and_then(|_| fd.sync_all()) {
+        Ok(_) => {
+            drop(fd);
+            hard_link(&temp_path, file_path)
+        }
+        Err(error) => Err(error),
+    };
+    remove_file(&temp_path).unwrap_or(());
+
+    match result {
+        Ok(_) => Ok(true),
+        Err(ref error) if error.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(_) => {
+            // file systems without hard links support, the content is written after creation
+            match OpenOptions::new().write(true).create_new(true).open(file_path) {
+                Ok(mut fd) => match fd.write_all(text.as_bytes()).and_then(|_| fd.sync_all()) {
+                    Ok(_) => Ok(true),
+                    Err(error) => {
+                        drop(fd);
+                        remove_file(file_path).unwrap_or(());
+
+                        Err(FsIOError::IOError(
+                                format!("Error while writing to file: {:?}", &file_path).to_string(),
+                                Some(error),
+                            ),
+                        )
+                    }
+                },
+                Err(ref error) if error.kind() == ErrorKind::AlreadyExists => Ok(false),
+                Err(error) => Err(FsIOError::IOError(
+                        format!("Unable to create lock file: {:?}", &file_path).to_string(),
+                        Some(error),
+                    ),
+                ),
+            }
+        }
+    }
+}
+
+/// Removes a stale lock file.
+/// The lock file is first renamed, so only one process can reclaim it, and in case it was
+/// replaced by a new lock in the meantime, it is restored.
+/// If the restore fails, the renamed lock file is left in place and an error is returned.
+fn reclaim_lock_file(file_path: &Path, stale_text: &str) -> Result<(), FsIOError> {
+    let (stale_path, fd) = create_sibling_temp_file(file_path)?;
+    drop(fd);
+
+    match rename(file_path, &stale_path) {
+        Ok(_) => {
+            let reclaimed_text = read_to_string(&stale_path).unwrap_or_default();
+            if reclaimed_text != stale_text {
+                if let Err(error) = hard_link(&stale_path, file_path) {
+                    return Err(FsIOError::IOError(
+                            format!(
+                                "Unable to restore lock file: {:?} from: {:?}",
+                                &file_path, &stale_path
+                            )
+                            .to_string(),
+                            Some(error),
+                        ),
+                    );
+                }
+            }
+
+            remove_file(&stale_path).unwrap_or(());
+            Ok(())
+        }
+        Err(ref error) if error.kind() == ErrorKind::NotFound => {
+            remove_file(&stale_path).unwrap_or(());
+            Ok(())
+        }
+        Err(error) => {
+            remove_file(&stale_path).unwrap_or(());
+
+            Err(FsIOError::IOError(
+                    format!("Unable to remove stale lock file: {:?}", &file_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    }
+}
+
+fn get_hostname() -> String {
+    if let Ok(hostname) = read_to_string("/proc/sys/kernel/hostname") {
+        let hostname = hostname.trim();
+        if !hostname.is_empty() {
+            return hostname.to_string();
+        }
+    }
+
+    ["HOSTNAME", "COMPUTERNAME"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|hostname| !hostname.is_empty())
+        .unwrap_or_default()
+}
+
+/// Returns the current process start time since boot in clock ticks and the system boot id.
+#[cfg(target_os = "linux")]
+fn get_current_process_identity() -> (Option<u64>, Option<String>) {
+    (get_process_start_ticks("self").ok().flatten(), get_boot_id())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_current_process_identity() -> (Option<u64>, Option<String>) {
+    (None, None)
+}
+
+/// Returns false only if the owner process is proven to no longer exist, in any other case
+/// (missing owner information, hidden or inaccessible processes) the owner is alive.
+#[cfg(target_os = "linux")]
+fn is_owner_alive(owner: &LockFileOwner) -> bool {
+    let (owner_start_ticks, owner_boot_id) = match (owner.process_start_ticks, &owner.boot_id) {
+        (Some(start_ticks), Some(boot_id)) => (start_ticks, boot_id),
+        _ => return true,
+    };
+
+    // the start time is relative to the boot, so it is only comparable on the same boot
+    match get_boot_id() {
+        Some(ref boot_id) if boot_id == owner_boot_id => (),
+        Some(_) => return false,
+        None => return true,
+    }
+
+    match get_process_start_ticks(&owner.pid.to_string()) {
+        // a different start time means the process id was reused
+        Ok(Some(start_ticks)) => start_ticks == owner_start_ticks,
+        Ok(None) => true,
+        // processes of other users might be hidden (hidepid), so a missing process is only
+        // dead if all processes are visible
+        Err(ref error) if error.kind() == ErrorKind::NotFound => {
+            match read_to_string("/proc/self/mountinfo") {
+                Ok(mount_info) => is_proc_hiding_processes(&mount_info),
+                Err(_) => true,
+            }
+        }
+        Err(_) => true,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_owner_alive(_owner: &LockFileOwner) -> bool {
+    true
+}
+
+/// Returns the process start time since boot in clock ticks, or a NotFound error if the
+/// process does not exist.
+#[cfg(target_os = "linux")]
+fn get_process_start_ticks(pid: &str) -> io::Result<Option<u64>> {
+    let stat = read_to_string(format!("/proc/{}/stat", pid))?;
+
+    // the process name is wrapped with parentheses and may contain spaces
+    let fields = match stat.rfind(')') {
+        Some(index) => stat[index + 1..].split_whitespace().collect::<Vec<&str>>(),
+        None => return Ok(None),
+    };
+
+    // the start time is the 22nd field, while the fields list starts at the 3rd field
+    Ok(fields.get(19).and_then(|value| value.parse().ok()))
+}
+
+#[cfg(target_os = "linux")]
+fn get_boot_id() -> Option<String> {
+    let boot_id = read_to_string("/proc/sys/kernel/random/boot_id").ok()?;
+    let boot_id = boot_id.trim();
+
+    if boot_id.is_empty() {
+        None
+    } else {
+        Some(boot_id.to_string())
+    }
+}
+
+/// Returns true if the /proc mount, based on the provided mount info, hides processes of other
+/// users via the hidepid option.
+#[cfg(target_os = "linux")]
+fn is_proc_hiding_processes(mount_info: &str) -> bool {
+    mount_info.lines().any(|line| {
+        // the file system type and super block options follow the separator
+        match line.split_once(" - ") {
+            Some((mount, file_system)) => {
+                let mount_point = mount.split_whitespace().nth(4);
+                let mut file_system_fields = file_system.split_whitespace();
+                let file_system_type = file_system_fields.next();
+                let options = file_system_fields.nth(1).unwrap_or("");
+
+                mount_point == Some("/proc")
+                    && file_system_type == Some("proc")
+                    && options.split(',').any(|option| match option.strip_prefix("hidepid=") {
+                        Some(value) => value != "0" && value != "off",
+                        None => false,
+                    })
+            }
+            None => false,
+        }
+    })
+}
+
+/// The amount of last read bytes compared to detect a rewritten file.
+const FOLLOW_CHECK_LENGTH: usize = 64;
+
+/// Holds the file follow options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FollowOptions {
+    /// How often the file is checked for new content, truncation and rotation (default 100ms)
+    pub poll_interval: Duration,
+    /// True to return the existing content of the file as well, otherwise only lines appended
+    /// after the follow started are returned (default false)
+    pub from_start: bool,
+    /// True to treat \r\n as a single line terminator (default true)
+    pub crlf: bool,
+}
+
+impl FollowOptions {
+    /// Returns new instance with default values.
+    pub fn new() -> FollowOptions {
+        FollowOptions {
+            poll_interval: Duration::from_millis(100),
+            from_start: false,
+            crlf: true,
+        }
+    }
+}
+
+impl Default for FollowOptions {
+    fn default() -> Self {
+        FollowOptions::new()
+    }
+}
+
+/// Handle which stops a [Follow](struct.Follow.html) iterator, can be sent to other threads.
+#[derive(Debug, Clone)]
+pub struct FollowStopHandle {
+    stopped: Arc<AtomicBool>,
+}
+
+impl FollowStopHandle {
+    /// Stops the iterator, which will end within one poll interval.
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+#[cfg(windows)]
+type FileId = SystemTime;
+
+#[cfg(not(windows))]
+type FileId = (u64, u64);
+
+#[cfg(windows)]
+fn get_file_id(file_metadata: &Metadata) -> Option<FileId> {
+    file_metadata.created().ok()
+}
+
+#[cfg(not(windows))]
+fn get_file_id(file_metadata: &Metadata) -> Option<FileId> {
+    use std::os::unix::fs::MetadataExt;
+
+    Some((file_metadata.dev(), file_metadata.ino()))
+}
+
+/// Iterator over the lines appended to a file, which blocks while waiting for new content.
+/// Created via the [follow](fn.follow.html) and [follow_with_options](fn.follow_with_options.html)
+/// functions.
+#[derive(Debug)]
+pub struct Follow {
+    path: PathBuf,
+    options: FollowOptions,
+    reader: Option<BufReader<File>>,
+    file_id: Option<FileId>,
+    position: u64,
+    last_data: Vec<u8>,
+    line: Vec<u8>,
+    opened: bool,
+    stopped: Arc<AtomicBool>,
+}
+
+impl Follow {
+    /// Returns a handle which can be used to stop the iterator from another thread.
+    pub fn stop_handle(&self) -> FollowStopHandle {
+        FollowStopHandle {
+            stopped: self.stopped.clone(),
+        }
+    }
+
+    /// Opens the file, returns false if it does not exist yet.
+    fn open(&mut self) -> Result<bool, FsIOError> {
+        let mut fd = match File::open(&self.path) {
+            Ok(fd) => fd,
+            Err(ref error) if error.kind() == ErrorKind::NotFound => return Ok(false),
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read file: {:?}", &self.path).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        };
+
+        // only the first opened file skips the existing content
+        let skip_content = !self.opened && !self.options.from_start;
+        let seek_result = if skip_content {
+            fd.seek(SeekFrom::End(0))
+        } else {
+            Ok(0)
+        };
+        self.position = match seek_result {
+            Ok(position) => position,
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read file: {:?}", &self.path).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        };
+
+        self.file_id = fd
+            .metadata()
+            .ok()
+            .and_then(|file_metadata| get_file_id(&file_metadata));
+        self.last_data.clear();
+        if self.position > 0 {
+            let length = min(self.position, FOLLOW_CHECK_LENGTH as u64);
+            let offset = self.position - length;
+            self.last_data = read_range_from_file(&fd, &self.path, offset, length as usize)?;
+            // positional reads move the file offset on windows
+            if let Err(error) = fd.seek(SeekFrom::Start(self.position)) {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read file: {:?}", &self.path).to_string(),
+                        Some(error),
+                    ),
+                );
+            }
+        }
+        self.reader = Some(BufReader::new(fd));
+        self.opened = true;
+
+        Ok(true)
+    }
+
+    /// Checks if the file was rotated or truncated once the end of the current file was reached.
+    /// Returns the incomplete last line of a rotated file, if any.
+    fn check_file(&mut self) -> Option<Vec<u8>> {
+        let file_metadata = match metadata(&self.path) {
+            Ok(value) => value,
+            // the file might be in the middle of a rotation, so the old file is kept until
+            // the new one is created
+            Err(_) => return None,
+        };
+
+        if get_file_id(&file_metadata) != self.file_id {
+            self.reader = None;
+
+            let line = self.line.split_off(0);
+            if line.is_empty() {
+                None
+            } else {
+                Some(line)
+            }
+        } else {
+            let rewritten = file_metadata.len() < self.position || self.is_last_data_changed();
+
+            if let Some(ref mut reader) = self.reader {
+                if rewritten {
+                    if reader.seek(SeekFrom::Start(0)).is_ok() {
+                        self.position = 0;
+                        self.last_data.clear();
+                        self.line.clear();
+                    }
+                } else {
+                    // positional reads move the file offset on windows
+                    reader.seek(SeekFrom::Start(self.position)).unwrap_or(0);
+                }
+            }
+
+            None
+        }
+    }
+
+    /// Returns true if the last bytes already read were modified, which detects a file
+    /// rewritten in place to the same or bigger size.
+    /// The content is compared instead of the modified time, as the modified time is also
+    /// updated by metadata only changes and before the size during writes.
+    fn is_last_data_changed(&self) -> bool {
+        if self.last_data.is_empty() {
+            return false;
+        }
+
+        match self.reader {
+            Some(ref reader) => {
+                let offset = self.position - self.last_data.len() as u64;
+                let length = self.last_data.len();
+                match read_range_from_file(reader.get_ref(), &self.path, offset, length) {
+                    Ok(data) => data != self.last_data,
+                    Err(_) => false,
+                }
+            }
+            None => false,
+        }
+    }
+
+    fn to_line(&self, mut buffer: Vec<u8>) -> Result<String, FsIOError> {
+        if buffer.ends_with(b"\n") {
+            buffer.pop();
+            if self.options.crlf && buffer.ends_with(b"\r") {
+                buffer.pop();
+            }
+        }
+
+        match String::from_utf8(buffer) {
+            Ok(line) => Ok(line),
+            Err(error) => Err(FsIOError::IOError(
+                    format!("Unable to read file: {:?}", &self.path).to_string(),
+                    Some(io::Error::new(ErrorKind::InvalidData, error)),
+                ),
+            ),
+        }
+    }
+}
+
+impl Iterator for Follow {
+    type Item = Result<String, FsIOError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.stopped.load(Ordering::SeqCst) {
+                return None;
+            }
+
+            if self.reader.is_none() {
+                match self.open() {
+                    Ok(true) => (),
+                    Ok(false) => {
+                        thread::sleep(self.options.poll_interval);
+                        continue;
+                    }
+                    Err(error) => {
+                        thread::sleep(self.options.poll_interval);
+                        return Some(Err(error));
+                    }
+                }
+            }
+
+            let result = match self.reader {
+                Some(ref mut reader) => reader.read_until(b'\n', &mut self.line),
+                None => continue,
+            };
+
+            match result {
+                Ok(0) => {
+                    if let Some(line) = self.check_file() {
+                        return Some(self.to_line(line));
+                    }
+
+                    thread::sleep(self.options.poll_interval);
+                }
+                Ok(size) => {
+                    self.position += size as u64;
+
+                    let read_data = &self.line[self.line.len() - size..];
+                    self.last_data.extend_from_slice(read_data);
+                    if self.last_data.len() > FOLLOW_CHECK_LENGTH {
+                        let extra_length = self.last_data.len() - FOLLOW_CHECK_LENGTH;
+                        self.last_data.drain(..extra_length);
+                    }
+
+                    if self.line.ends_with(b"\n") {
+                        let line = self.line.split_off(0);
+                        return Some(self.to_line(line));
+                    }
+                }
+                Err(error) => {
+                    // reopen the file on the next call
+                    self.reader = None;
+                    self.line.clear();
+
+                    return Some(Err(FsIOError::IOError(
+                            format!("Unable to read file: {:?}", &self.path).to_string(),
+                            Some(error),
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Returns an iterator which follows the file (like tail -F) and returns the lines appended
+/// to it, without their line terminators.
+/// The iterator blocks while waiting for new lines and handles file truncation and rotation
+/// (replacing the file with a new one) by reading the new content from the start.
+/// If the file does not exist, it waits for it to be created.
+/// The iterator ends only once stopped via its [stop handle](struct.Follow.html#method.stop_handle).
+///
+/// # Arguments
+///
+/// * `path` - The file path
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use std::thread;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/follow/build.log";
+///     file::write_text_file(file_path, "old line\n").unwrap();
+///
+///     let lines = file::follow(file_path);
+///     let stop_handle = lines.stop_handle();
+///
+///     let writer = thread::spawn(move || {
+///         file::append_text_file(file_path, "new line\n").unwrap();
+///     });
+///
+///     for line in lines {
+///         assert_eq!(line.unwrap(), "new line");
+///         stop_handle.stop();
+///     }
+///
+///     writer.join().unwrap();
+/// }
+/// ```
+pub fn follow<T: AsPath + ?Sized>(path: &T) -> Follow {
+    follow_with_options(path, &FollowOptions::new())
+}
+
+/// Returns an iterator which follows the file based on the provided options.
+/// See [follow](fn.follow.html) for more details.
+///
+/// # Arguments
+///
+/// * `path` - The file path
+/// * `options` - The follow options
+///
+/// # Example
+///
+/// ```
+/// use crate::fsio::file;
+/// use crate::fsio::file::FollowOptions;
+/// use std::time::Duration;
+///
+/// fn main() {
+///     let file_path = "./target/__test/file_test/follow_with_options/build.log";
+///     file::write_text_file(file_path, "first\r\nsecond\n").unwrap();
+///
+///     let mut options = FollowOptions::new();
+///     options.poll_interval = Duration::from_millis(10);
+///     options.from_start = true;
+///     let lines: Vec<String> = file::follow_with_options(file_path, &options)
+///         .take(2)
+///         .map(|line| line.unwrap())
+///         .collect();
+///
+///     assert_eq!(lines, vec!["first", "second"]);
+/// }
+/// ```
+pub fn follow_with_options<T: AsPath + ?Sized>(path: &T, options: &FollowOptions) -> Follow {
+    let mut follow = Follow {
+        path: path.as_path().to_path_buf(),
+        options: *options,
+        reader: None,
+        file_id: None,
+        position: 0,
+        last_data: vec![],
+        line: vec![],
+        opened: false,
+        stopped: Arc::new(AtomicBool::new(false)),
+    };
+
+    // open right away so lines appended from now on are returned, if the file does not exist
+    // yet (or fails to open), it is opened while iterating
+    match follow.open() {
+        Ok(true) => (),
+        _ => follow.opened = true,
+    };
+
+    follow
 }
@@ -1,8 +1,9 @@
 use super::*;
 
-use std::io::ErrorKind;
+use std::fs::read_dir;
 use std::path::Path;
 use std::str;
+use std::sync::mpsc;
 
 #[test]
 fn ensure_exists_not_exists() {
@@ -152,6 +153,100 @@ fn write_file_on_directory() {
     assert!(result.is_err());
 }
 
+#[test]
+fn write_file_atomic_not_exists() {
+    let file_path =
+        "./target/__test/ut/file_test/write_file_atomic/write_file_atomic_not_exists/file.txt";
+    let result = write_file_atomic(file_path, "some content".as_bytes());
+    assert!(result.is_ok());
+
+    let text = read_text_file(file_path).unwrap();
+
+    assert_eq!(text, "some content");
+}
+
+#[test]
+fn write_file_atomic_exists() {
+    let file_path =
+        "./target/__test/ut/file_test/write_file_atomic/write_file_atomic_exists/file.txt";
+    let mut result = write_file_atomic(file_path, "some content 1".as_bytes());
+    assert!(result.is_ok());
+    result = write_file_atomic(file_path, "some content 2".as_bytes());
+    assert!(result.is_ok());
+
+    let text = read_text_file(file_path).unwrap();
+
+    assert_eq!(text, "some content 2");
+
+    let entries = read_dir(
+        "./target/__test/ut/file_test/write_file_atomic/write_file_atomic_exists",
+    )
+    .unwrap()
+    .count();
+    assert_eq!(entries, 1);
+}
+
+#[test]
+#[cfg(not(windows))]
+fn write_file_atomic_preserve_permissions() {
+    use std::fs::{set_permissions, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    let file_path =
+        "./target/__test/ut/file_test/write_file_atomic/write_file_atomic_preserve_permissions/file.txt";
+    let mut result = write_file(file_path, "some content 1".as_bytes());
+    assert!(result.is_ok());
+    set_permissions(file_path, Permissions::from_mode(0o640)).unwrap();
+
+    result = write_file_atomic(file_path, "some content 2".as_bytes());
+    assert!(result.is_ok());
+
+    let mode = metadata(file_path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o640);
+    assert_eq!(read_text_file(file_path).unwrap(), "some content 2");
+}
+
+#[test]
+#[cfg(not(windows))]
+fn write_file_atomic_symlink() {
+    use std::os::unix::fs::symlink;
+
+    let directory = "./target/__test/ut/file_test/write_file_atomic/write_file_atomic_symlink";
+    directory::delete(directory).unwrap();
+    let file_path = format!("{}/target/file.txt", directory);
+    let link_path = format!("{}/link.txt", directory);
+    write_file(&file_path, "some content 1".as_bytes()).unwrap();
+    symlink("target/file.txt", &link_path).unwrap();
+
+    let result = write_file_atomic(&link_path, "some content 2".as_bytes());
+    assert!(result.is_ok());
+
+    assert!(symlink_metadata(&link_path).unwrap().file_type().is_symlink());
+    assert_eq!(read_text_file(&file_path).unwrap(), "some content 2");
+    assert_eq!(read_dir(&format!("{}/target", directory)).unwrap().count(), 1);
+}
+
+#[test]
+fn write_file_atomic_on_directory() {
+    let file_path = "./target/__test/ut/file_test/write_file_atomic/write_file_atomic_on_directory";
+    let mut result = directory::create(file_path);
+    assert!(result.is_ok());
+    result = write_file_atomic(file_path, "some content".as_bytes());
+    assert!(result.is_err());
+}
+
+#[test]
+fn write_text_file_atomic_not_exists() {
+    let file_path =
+        "./target/__test/ut/file_test/write_text_file_atomic/write_text_file_atomic_not_exists/file.txt";
+    let result = write_text_file_atomic(file_path, "some content");
+    assert!(result.is_ok());
+
+    let text = read_text_file(file_path).unwrap();
+
+    assert_eq!(text, "some content");
+}
+
 #[test]
 fn append_file_not_exists() {
     let file_path = "./target/__test/ut/file_test/append_file/append_file_not_exists/file.txt";
@@ -216,6 +311,88 @@ fn read_text_file_exists() {
     assert_eq!(text, "some content");
 }
 
+#[test]
+fn read_lines_not_exists() {
+    let file_path = "./target/__test/ut/file_test/read_lines/read_lines_not_exists/file.txt";
+    let result = read_lines(file_path);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn read_lines_empty() {
+    let file_path = "./target/__test/ut/file_test/read_lines/read_lines_empty/file.txt";
+    let result = write_text_file(file_path, "");
+    assert!(result.is_ok());
+
+    let count = read_lines(file_path).unwrap().count();
+
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn read_lines_strip_terminators() {
+    let file_path =
+        "./target/__test/ut/file_test/read_lines/read_lines_strip_terminators/file.txt";
+    let result = write_text_file(file_path, "line 1\n\nline 2\r\nline 3\n");
+    assert!(result.is_ok());
+
+    let lines: Vec<String> = read_lines(file_path)
+        .unwrap()
+        .map(|line| line.unwrap())
+        .collect();
+
+    assert_eq!(lines, vec!["line 1", "", "line 2", "line 3"]);
+}
+
+#[test]
+fn read_lines_keep_carriage_return() {
+    let file_path =
+        "./target/__test/ut/file_test/read_lines/read_lines_keep_carriage_return/file.txt";
+    let result = write_text_file(file_path, "line 1\r\nline 2");
+    assert!(result.is_ok());
+
+    let mut options = ReadLinesOptions::new();
+    options.crlf = false;
+    let lines: Vec<String> = read_lines_with_options(file_path, &options)
+        .unwrap()
+        .map(|line| line.unwrap())
+        .collect();
+
+    assert_eq!(lines, vec!["line 1\r", "line 2"]);
+}
+
+#[test]
+fn read_lines_keep_terminators() {
+    let file_path =
+        "./target/__test/ut/file_test/read_lines/read_lines_keep_terminators/file.txt";
+    let result = write_text_file(file_path, "line 1\nline 2\r\n");
+    assert!(result.is_ok());
+
+    let mut options = ReadLinesOptions::new();
+    options.keep_line_terminator = true;
+    let lines: Vec<String> = read_lines_with_options(file_path, &options)
+        .unwrap()
+        .map(|line| line.unwrap())
+        .collect();
+
+    assert_eq!(lines, vec!["line 1\n", "line 2\r\n"]);
+}
+
+#[test]
+fn read_lines_invalid_utf8() {
+    let file_path = "./target/__test/ut/file_test/read_lines/read_lines_invalid_utf8/file.txt";
+    let result = write_file(file_path, &[b'a', b'\n', 0xff, 0xfe, b'\n', b'b']);
+    assert!(result.is_ok());
+
+    let lines: Vec<Result<String, FsIOError>> = read_lines(file_path).unwrap().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0].as_ref().unwrap(), "a");
+    assert!(lines[1].is_err());
+    assert_eq!(lines[2].as_ref().unwrap(), "b");
+}
+
 #[test]
 fn read_file_not_exists() {
     let file_path = "./target/__test/ut/file_test/read_file/read_text_file_not_exists/file.txt";
@@ -322,3 +499,1073 @@ fn delete_ignore_error_directory() {
 
     assert!(path.exists());
 }
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_all_algorithms() {
+    let file_path = "./target/__test/ut/file_test/hash_all_algorithms/file.txt";
+    write_text_file(file_path, "some content").unwrap();
+
+    assert_eq!(
+        hash(file_path, HashAlgorithm::Sha256).unwrap(),
+        "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56"
+    );
+    assert_eq!(
+        hash(file_path, HashAlgorithm::Sha1).unwrap(),
+        "94e66df8cd09d410c62d9e0dc59d3a884e458e05"
+    );
+    assert_eq!(
+        hash(file_path, HashAlgorithm::Md5).unwrap(),
+        "9893532233caff98cd083a116b013c0b"
+    );
+    assert_eq!(hash(file_path, HashAlgorithm::Crc32).unwrap(), "431f313f");
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_large_file() {
+    let file_path = "./target/__test/ut/file_test/hash_large_file/file.bin";
+    let data = vec![7; 200 * 1024];
+    write_file(file_path, &data).unwrap();
+
+    let mut hasher = Hasher::new(HashAlgorithm::Sha256);
+    hasher.update(&data);
+
+    assert_eq!(
+        hash(file_path, HashAlgorithm::Sha256).unwrap(),
+        hasher.finish()
+    );
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_not_found() {
+    let result = hash(
+        "./target/__test/ut/file_test/hash_not_found/file.txt",
+        HashAlgorithm::Sha256,
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn write_file_if_changed_not_exists() {
+    let file_path = "./target/__test/ut/file_test/write_file_if_changed_not_exists/file.txt";
+    delete_ignore_error(file_path);
+
+    let written = write_file_if_changed(file_path, "some content".as_bytes()).unwrap();
+
+    assert!(written);
+    assert_eq!(read_text_file(file_path).unwrap(), "some content");
+}
+
+#[test]
+fn write_file_if_changed_same_content() {
+    let file_path = "./target/__test/ut/file_test/write_file_if_changed_same_content/file.txt";
+    write_file(file_path, "some content".as_bytes()).unwrap();
+    let modified = metadata(file_path).unwrap().modified().unwrap();
+
+    let written = write_file_if_changed(file_path, "some content".as_bytes()).unwrap();
+
+    assert!(!written);
+    assert_eq!(metadata(file_path).unwrap().modified().unwrap(), modified);
+}
+
+#[test]
+fn write_file_if_changed_same_length() {
+    let file_path = "./target/__test/ut/file_test/write_file_if_changed_same_length/file.txt";
+    write_file(file_path, "some content".as_bytes()).unwrap();
+
+    let written = write_file_if_changed(file_path, "more content".as_bytes()).unwrap();
+
+    assert!(written);
+    assert_eq!(read_text_file(file_path).unwrap(), "more content");
+}
+
+#[test]
+fn write_file_if_changed_large_file() {
+    let file_path = "./target/__test/ut/file_test/write_file_if_changed_large_file/file.bin";
+    let mut data = vec![1; 200 * 1024];
+    write_file(file_path, &data).unwrap();
+
+    let written = write_file_if_changed(file_path, &data).unwrap();
+    assert!(!written);
+
+    data[150 * 1024] = 2;
+    let written = write_file_if_changed(file_path, &data).unwrap();
+    assert!(written);
+    assert_eq!(read_file(file_path).unwrap(), data);
+}
+
+#[test]
+fn write_file_if_changed_directory() {
+    let directory_path = "./target/__test/ut/file_test/write_file_if_changed_directory";
+    directory::create(directory_path).unwrap();
+
+    let result = write_file_if_changed(directory_path, "".as_bytes());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn write_text_file_if_changed_valid() {
+    let file_path = "./target/__test/ut/file_test/write_text_file_if_changed_valid/file.txt";
+
+    assert!(write_text_file_if_changed(file_path, "some content").unwrap());
+    assert!(!write_text_file_if_changed(file_path, "some content").unwrap());
+    assert!(write_text_file_if_changed(file_path, "some content\n").unwrap());
+    assert_eq!(read_text_file(file_path).unwrap(), "some content\n");
+}
+
+#[test]
+fn copy_valid() {
+    let directory = "./target/__test/ut/file_test/copy/copy_valid";
+    let source = format!("{}/source.txt", directory);
+    let target = format!("{}/dir1/dir2/target.txt", directory);
+    write_text_file(&source, "some content").unwrap();
+
+    let size = copy(&source, &target, &CopyOptions::new()).unwrap();
+
+    assert_eq!(size, 12);
+    assert_eq!(read_text_file(&target).unwrap(), "some content");
+}
+
+#[test]
+fn copy_large_file_overwrite() {
+    let directory = "./target/__test/ut/file_test/copy/copy_large_file_overwrite";
+    let source = format!("{}/source.bin", directory);
+    let target = format!("{}/target.bin", directory);
+    let data: Vec<u8> = (0..3 * 1024 * 1024).map(|index| (index % 251) as u8).collect();
+    write_file(&source, &data).unwrap();
+    write_file(&target, &vec![1; 4 * 1024 * 1024]).unwrap();
+
+    let size = copy(&source, &target, &CopyOptions::new()).unwrap();
+
+    assert_eq!(size, data.len() as u64);
+    assert_eq!(read_file(&target).unwrap(), data);
+}
+
+#[test]
+fn copy_empty_file() {
+    let directory = "./target/__test/ut/file_test/copy/copy_empty_file";
+    let source = format!("{}/source.txt", directory);
+    let target = format!("{}/target.txt", directory);
+    write_text_file(&source, "").unwrap();
+    write_text_file(&target, "some content").unwrap();
+
+    let size = copy(&source, &target, &CopyOptions::new()).unwrap();
+
+    assert_eq!(size, 0);
+    assert_eq!(read_text_file(&target).unwrap(), "");
+}
+
+#[test]
+fn copy_without_create_parent() {
+    let directory = "./target/__test/ut/file_test/copy/copy_without_create_parent";
+    let source = format!("{}/source.txt", directory);
+    write_text_file(&source, "some content").unwrap();
+
+    let mut options = CopyOptions::new();
+    options.create_parent = false;
+    let result = copy(&source, &format!("{}/dir/target.txt", directory), &options);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn copy_source_not_found() {
+    let directory = "./target/__test/ut/file_test/copy/copy_source_not_found";
+
+    let result = copy(
+        &format!("{}/source.txt", directory),
+        &format!("{}/target.txt", directory),
+        &CopyOptions::new(),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn copy_source_directory() {
+    let directory = "./target/__test/ut/file_test/copy/copy_source_directory";
+    directory::create(&format!("{}/source", directory)).unwrap();
+
+    let result = copy(
+        &format!("{}/source", directory),
+        &format!("{}/target", directory),
+        &CopyOptions::new(),
+    );
+
+    match result {
+        Err(FsIOError::NotFile(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+fn copy_same_file() {
+    let directory = "./target/__test/ut/file_test/copy/copy_same_file";
+    let source = format!("{}/source.txt", directory);
+    write_text_file(&source, "some content").unwrap();
+
+    let result = copy(
+        &source,
+        &format!("{}/../copy_same_file/source.txt", directory),
+        &CopyOptions::new(),
+    );
+
+    assert!(result.is_err());
+    assert_eq!(read_text_file(&source).unwrap(), "some content");
+}
+
+#[test]
+fn copy_preserve_modified_time() {
+    let directory = "./target/__test/ut/file_test/copy/copy_preserve_modified_time";
+    let source = format!("{}/source.txt", directory);
+    write_text_file(&source, "some content").unwrap();
+    let modified = SystemTime::now() - Duration::from_secs(3600);
+    File::options()
+        .write(true)
+        .open(&source)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+
+    let mut options = CopyOptions::new();
+    let target = format!("{}/target1.txt", directory);
+    copy(&source, &target, &options).unwrap();
+    assert_ne!(metadata(&target).unwrap().modified().unwrap(), modified);
+
+    options.preserve_modified_time = true;
+    let target = format!("{}/target2.txt", directory);
+    copy(&source, &target, &options).unwrap();
+    assert_eq!(metadata(&target).unwrap().modified().unwrap(), modified);
+}
+
+#[test]
+#[cfg(not(windows))]
+fn copy_preserve_permissions() {
+    use std::fs::{set_permissions, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    let directory = "./target/__test/ut/file_test/copy/copy_preserve_permissions";
+    let source = format!("{}/source.txt", directory);
+    write_text_file(&source, "some content").unwrap();
+    set_permissions(&source, Permissions::from_mode(0o751)).unwrap();
+
+    let target = format!("{}/target.txt", directory);
+    copy(&source, &target, &CopyOptions::new()).unwrap();
+
+    assert_eq!(metadata(&target).unwrap().permissions().mode() & 0o777, 0o751);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn copy_special_file() {
+    let target = "./target/__test/ut/file_test/copy/copy_special_file/status.txt";
+
+    let size = copy("/proc/self/status", target, &CopyOptions::new()).unwrap();
+
+    assert!(size > 0);
+    assert!(read_text_file(target).unwrap().contains("Pid:"));
+}
+
+#[test]
+fn move_file_valid() {
+    let directory = "./target/__test/ut/file_test/move_file/move_file_valid";
+    let source = format!("{}/source.txt", directory);
+    let target = format!("{}/dir/target.txt", directory);
+    write_text_file(&source, "some content").unwrap();
+
+    move_file(&source, &target).unwrap();
+
+    assert!(!Path::new(&source).exists());
+    assert_eq!(read_text_file(&target).unwrap(), "some content");
+}
+
+#[test]
+fn move_file_overwrite() {
+    let directory = "./target/__test/ut/file_test/move_file/move_file_overwrite";
+    let source = format!("{}/source.txt", directory);
+    let target = format!("{}/target.txt", directory);
+    write_text_file(&source, "some content").unwrap();
+    write_text_file(&target, "old content").unwrap();
+
+    move_file(&source, &target).unwrap();
+
+    assert!(!Path::new(&source).exists());
+    assert_eq!(read_text_file(&target).unwrap(), "some content");
+}
+
+#[test]
+fn move_file_not_found() {
+    let directory = "./target/__test/ut/file_test/move_file/move_file_not_found";
+
+    let result = move_file(
+        &format!("{}/source.txt", directory),
+        &format!("{}/target.txt", directory),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn move_file_directory() {
+    let directory = "./target/__test/ut/file_test/move_file/move_file_directory";
+    directory::create(&format!("{}/source", directory)).unwrap();
+
+    let result = move_file(
+        &format!("{}/source", directory),
+        &format!("{}/target", directory),
+    );
+
+    assert!(result.is_err());
+    assert!(Path::new(&format!("{}/source", directory)).is_dir());
+}
+
+#[test]
+fn move_file_by_copy_valid() {
+    let directory = "./target/__test/ut/file_test/move_file/move_file_by_copy_valid";
+    let source = format!("{}/source.txt", directory);
+    let target = format!("{}/target.txt", directory);
+    write_text_file(&source, "some content").unwrap();
+    let modified = metadata(&source).unwrap().modified().unwrap();
+
+    move_file_by_copy(Path::new(&source), Path::new(&target)).unwrap();
+
+    assert!(!Path::new(&source).exists());
+    assert_eq!(read_text_file(&target).unwrap(), "some content");
+    assert_eq!(metadata(&target).unwrap().modified().unwrap(), modified);
+}
+
+#[test]
+#[cfg(not(windows))]
+fn move_file_symlink() {
+    use std::os::unix::fs::symlink;
+
+    let directory = "./target/__test/ut/file_test/move_file/move_file_symlink";
+    directory::delete(directory).unwrap();
+    directory::create(directory).unwrap();
+    let source = format!("{}/source.txt", directory);
+    let target = format!("{}/target.txt", directory);
+    symlink("missing.txt", &source).unwrap();
+
+    move_file(&source, &target).unwrap();
+
+    assert!(symlink_metadata(&source).is_err());
+    assert_eq!(read_link(&target).unwrap(), Path::new("missing.txt"));
+}
+
+#[test]
+#[cfg(not(windows))]
+fn move_symlink_by_copy_valid() {
+    use std::os::unix::fs::symlink;
+
+    let directory = "./target/__test/ut/file_test/move_file/move_symlink_by_copy_valid";
+    directory::delete(directory).unwrap();
+    write_text_file(&format!("{}/file.txt", directory), "some content").unwrap();
+    let source = format!("{}/source.txt", directory);
+    let target = format!("{}/target.txt", directory);
+    symlink("file.txt", &source).unwrap();
+    write_text_file(&target, "old content").unwrap();
+
+    move_symlink_by_copy(Path::new(&source), Path::new(&target)).unwrap();
+
+    assert!(symlink_metadata(&source).is_err());
+    assert!(symlink_metadata(&target).unwrap().file_type().is_symlink());
+    assert_eq!(read_link(&target).unwrap(), Path::new("file.txt"));
+    assert_eq!(read_text_file(&format!("{}/file.txt", directory)).unwrap(), "some content");
+}
+
+#[test]
+fn is_same_content_valid() {
+    let directory = "./target/__test/ut/file_test/is_same_content_valid";
+    let first = format!("{}/first.bin", directory);
+    let second = format!("{}/second.bin", directory);
+    let third = format!("{}/third.bin", directory);
+    let mut data = vec![1; 100 * 1024];
+    write_file(&first, &data).unwrap();
+    write_file(&second, &data).unwrap();
+    data[90 * 1024] = 2;
+    write_file(&third, &data).unwrap();
+
+    assert!(is_same_content(Path::new(&first), Path::new(&second)).unwrap());
+    assert!(!is_same_content(Path::new(&first), Path::new(&third)).unwrap());
+
+    data.push(1);
+    write_file(&third, &data).unwrap();
+    assert!(!is_same_content(Path::new(&first), Path::new(&third)).unwrap());
+
+    let missing = format!("{}/missing.bin", directory);
+    assert!(is_same_content(Path::new(&first), Path::new(&missing)).is_err());
+}
+
+#[test]
+fn lock_exclusive_creates_file() {
+    let file_path = "./target/__test/ut/file_test/lock/lock_exclusive_creates_file/dir/state.lock";
+
+    let lock = lock_exclusive(file_path).unwrap();
+
+    assert!(Path::new(file_path).is_file());
+    assert_eq!(lock.path(), Path::new(file_path));
+}
+
+#[test]
+fn lock_exclusive_blocks_other_locks() {
+    let file_path =
+        "./target/__test/ut/file_test/lock/lock_exclusive_blocks_other_locks/state.lock";
+
+    let lock = lock_exclusive(file_path).unwrap();
+
+    assert!(try_lock_exclusive(file_path).unwrap().is_none());
+    assert!(try_lock_shared(file_path).unwrap().is_none());
+
+    drop(lock);
+
+    assert!(try_lock_exclusive(file_path).unwrap().is_some());
+}
+
+#[test]
+fn lock_shared_allows_shared_locks() {
+    let file_path = "./target/__test/ut/file_test/lock/lock_shared_allows_shared_locks/state.lock";
+
+    let lock1 = lock_shared(file_path).unwrap();
+    let lock2 = try_lock_shared(file_path).unwrap();
+
+    assert!(lock2.is_some());
+    assert!(try_lock_exclusive(file_path).unwrap().is_none());
+
+    drop(lock1);
+    drop(lock2);
+
+    assert!(try_lock_exclusive(file_path).unwrap().is_some());
+}
+
+#[test]
+fn lock_exclusive_waits_for_release() {
+    let file_path = "./target/__test/ut/file_test/lock/lock_exclusive_waits_for_release/state.lock";
+
+    let lock = lock_exclusive(file_path).unwrap();
+    let handle = thread::spawn(move || {
+        let lock = lock_exclusive(file_path).unwrap();
+        write_text_file(file_path, "second").unwrap();
+        drop(lock);
+    });
+
+    thread::sleep(Duration::from_millis(50));
+    write_text_file(file_path, "first").unwrap();
+    drop(lock);
+    handle.join().unwrap();
+
+    assert_eq!(read_text_file(file_path).unwrap(), "second");
+}
+
+#[test]
+fn lock_exclusive_timeout_expired() {
+    let file_path = "./target/__test/ut/file_test/lock/lock_exclusive_timeout_expired/state.lock";
+
+    let _lock = lock_exclusive(file_path).unwrap();
+    let start_time = Instant::now();
+    let result = lock_exclusive_timeout(file_path, Duration::from_millis(50)).unwrap();
+
+    assert!(result.is_none());
+    assert!(start_time.elapsed() >= Duration::from_millis(50));
+}
+
+#[test]
+fn lock_shared_timeout_acquired_after_release() {
+    let file_path =
+        "./target/__test/ut/file_test/lock/lock_shared_timeout_acquired_after_release/state.lock";
+
+    let lock = lock_exclusive(file_path).unwrap();
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        drop(lock);
+    });
+
+    let result = lock_shared_timeout(file_path, Duration::from_secs(10)).unwrap();
+    handle.join().unwrap();
+
+    assert!(result.is_some());
+}
+
+#[test]
+fn lock_directory() {
+    let directory_path = "./target/__test/ut/file_test/lock/lock_directory";
+    directory::create(directory_path).unwrap();
+
+    let result = lock_exclusive(directory_path);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn lock_file_acquire_and_drop() {
+    let file_path = "./target/__test/ut/file_test/lock_file/lock_file_acquire_and_drop/run.lock";
+
+    let lock = LockFile::acquire(file_path).unwrap();
+
+    assert_eq!(lock.path(), Path::new(file_path));
+    assert_eq!(lock.owner().pid, process::id());
+    let owner = LockFile::read_owner(file_path).unwrap();
+    assert_eq!(&owner, lock.owner());
+
+    drop(lock);
+
+    assert!(!Path::new(file_path).exists());
+}
+
+#[test]
+fn lock_file_held() {
+    let file_path = "./target/__test/ut/file_test/lock_file/lock_file_held/run.lock";
+
+    let _lock = LockFile::acquire(file_path).unwrap();
+    let result = LockFile::acquire(file_path);
+
+    match result {
+        Err(FsIOError::PathAlreadyExists(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+    assert!(Path::new(file_path).exists());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn lock_file_stale_reclaimed() {
+    let file_path = "./target/__test/ut/file_test/lock_file/lock_file_stale_reclaimed/run.lock";
+    let mut stale_owner = LockFileOwner::current();
+    // pid_max can not exceed 2^22 so this process can not exist
+    stale_owner.pid = 5000000;
+    write_text_file(file_path, &stale_owner.to_text()).unwrap();
+
+    let lock = LockFile::acquire(file_path).unwrap();
+
+    assert_eq!(LockFile::read_owner(file_path).unwrap().pid, process::id());
+    drop(lock);
+    assert!(!Path::new(file_path).exists());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn lock_file_reused_pid_reclaimed() {
+    let file_path =
+        "./target/__test/ut/file_test/lock_file/lock_file_reused_pid_reclaimed/run.lock";
+    let mut stale_owner = LockFileOwner::current();
+    // the owner process id was reused by the current process
+    stale_owner.process_start_ticks = stale_owner.process_start_ticks.map(|ticks| ticks + 1);
+    write_text_file(file_path, &stale_owner.to_text()).unwrap();
+
+    let lock = LockFile::acquire(file_path).unwrap();
+
+    assert_ne!(LockFile::read_owner(file_path).unwrap(), stale_owner);
+    assert_eq!(&LockFile::read_owner(file_path).unwrap(), lock.owner());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn lock_file_rebooted_reclaimed() {
+    let file_path = "./target/__test/ut/file_test/lock_file/lock_file_rebooted_reclaimed/run.lock";
+    let mut stale_owner = LockFileOwner::current();
+    stale_owner.boot_id = Some("other".to_string());
+    write_text_file(file_path, &stale_owner.to_text()).unwrap();
+
+    let lock = LockFile::acquire(file_path).unwrap();
+
+    assert_eq!(&LockFile::read_owner(file_path).unwrap(), lock.owner());
+}
+
+#[test]
+fn lock_file_unknown_owner_state_not_reclaimed() {
+    let file_path =
+        "./target/__test/ut/file_test/lock_file/lock_file_unknown_owner_state_not_reclaimed/run.lock";
+    let mut owner = LockFileOwner::current();
+    owner.pid = 5000000;
+    owner.process_start_ticks = None;
+    write_text_file(file_path, &owner.to_text()).unwrap();
+
+    assert!(LockFile::acquire(file_path).is_err());
+    assert_eq!(LockFile::read_owner(file_path).unwrap(), owner);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn is_proc_hiding_processes_options() {
+    let visible = "22 1 0:5 / /proc rw,nosuid - proc proc rw\n\
+                   23 1 0:6 / /sys rw - sysfs sysfs rw,hidepid=2\n";
+    let hidden = "22 1 0:5 / /proc rw,nosuid - proc proc rw,hidepid=invisible\n";
+    let disabled = "22 1 0:5 / /proc rw,nosuid - proc proc rw,hidepid=0\n";
+
+    assert!(!is_proc_hiding_processes(visible));
+    assert!(is_proc_hiding_processes(hidden));
+    assert!(!is_proc_hiding_processes(disabled));
+    assert!(!is_proc_hiding_processes(""));
+}
+
+#[test]
+fn lock_file_reclaim_restores_replaced_lock() {
+    let file_path =
+        "./target/__test/ut/file_test/lock_file/lock_file_reclaim_restores_replaced_lock/run.lock";
+    let owner = LockFileOwner::current();
+    write_text_file(file_path, &owner.to_text()).unwrap();
+
+    reclaim_lock_file(Path::new(file_path), "pid=1\nhostname=host\nstart_time=1\n").unwrap();
+
+    assert_eq!(LockFile::read_owner(file_path).unwrap(), owner);
+}
+
+#[test]
+fn get_hostname_not_empty() {
+    assert!(!get_hostname().is_empty());
+}
+
+#[test]
+fn lock_file_other_host_not_reclaimed() {
+    let file_path =
+        "./target/__test/ut/file_test/lock_file/lock_file_other_host_not_reclaimed/run.lock";
+    let mut owner = LockFileOwner::current();
+    owner.pid = 5000000;
+    owner.hostname = format!("{}-other", owner.hostname);
+    write_text_file(file_path, &owner.to_text()).unwrap();
+
+    let result = LockFile::acquire(file_path);
+
+    assert!(result.is_err());
+    assert_eq!(LockFile::read_owner(file_path).unwrap(), owner);
+}
+
+#[test]
+fn lock_file_invalid_content() {
+    let file_path = "./target/__test/ut/file_test/lock_file/lock_file_invalid_content/run.lock";
+    write_text_file(file_path, "test").unwrap();
+
+    assert!(LockFile::acquire(file_path).is_err());
+    assert!(LockFile::read_owner(file_path).is_err());
+    assert_eq!(read_text_file(file_path).unwrap(), "test");
+}
+
+#[test]
+fn lock_file_drop_keeps_other_owner() {
+    let file_path =
+        "./target/__test/ut/file_test/lock_file/lock_file_drop_keeps_other_owner/run.lock";
+    let lock = LockFile::acquire(file_path).unwrap();
+
+    let mut other_owner = LockFileOwner::current();
+    other_owner.start_time += 1;
+    write_text_file(file_path, &other_owner.to_text()).unwrap();
+    drop(lock);
+
+    assert_eq!(LockFile::read_owner(file_path).unwrap(), other_owner);
+}
+
+#[test]
+fn lock_file_owner_parse() {
+    let owner = LockFileOwner {
+        pid: 123,
+        hostname: "host".to_string(),
+        start_time: 456,
+        process_start_ticks: Some(789),
+        boot_id: Some("id".to_string()),
+    };
+
+    let text = owner.to_text();
+    assert_eq!(
+        text,
+        "pid=123\nhostname=host\nstart_time=456\nprocess_start_ticks=789\nboot_id=id\n"
+    );
+    assert_eq!(LockFileOwner::parse(&text).unwrap(), owner);
+
+    let owner = LockFileOwner::parse("pid=123\nhostname=host\nstart_time=456\n").unwrap();
+    assert_eq!(owner.process_start_ticks, None);
+    assert_eq!(owner.boot_id, None);
+    assert!(LockFileOwner::parse("pid=123\nhostname=host\n").is_none());
+    assert!(LockFileOwner::parse("pid=abc\nhostname=host\nstart_time=456\n").is_none());
+}
+
+#[test]
+#[cfg(feature = "mmap")]
+#[allow(unsafe_code)]
+fn map_file_valid() {
+    let file_path = "./target/__test/ut/file_test/map_file/map_file_valid/file.bin";
+    let data: Vec<u8> = (0..100 * 1024).map(|index| (index % 251) as u8).collect();
+    write_file(file_path, &data).unwrap();
+
+    let content = unsafe { map_file(file_path) }.unwrap();
+
+    assert!(content.is_mapped());
+    assert_eq!(&content[..], &data[..]);
+    assert_eq!(content.as_ref().len(), data.len());
+}
+
+#[test]
+#[cfg(feature = "mmap")]
+#[allow(unsafe_code)]
+fn map_file_empty() {
+    let file_path = "./target/__test/ut/file_test/map_file/map_file_empty/file.txt";
+    write_file(file_path, &[]).unwrap();
+
+    let content = unsafe { map_file(file_path) }.unwrap();
+
+    assert!(!content.is_mapped());
+    assert!(content.is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+#[allow(unsafe_code)]
+fn map_file_special_file() {
+    let content = unsafe { map_file("/proc/self/status") }.unwrap();
+
+    assert!(!content.is_mapped());
+    assert!(str::from_utf8(&content).unwrap().contains("Pid:"));
+}
+
+#[test]
+#[cfg(feature = "mmap")]
+#[allow(unsafe_code)]
+fn map_file_not_found() {
+    let file_path = "./target/__test/ut/file_test/map_file/map_file_not_found/file.txt";
+
+    let result = unsafe { map_file(file_path) };
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "mmap")]
+#[allow(unsafe_code)]
+fn map_file_directory() {
+    let directory_path = "./target/__test/ut/file_test/map_file/map_file_directory";
+    directory::create(directory_path).unwrap();
+
+    let result = unsafe { map_file(directory_path) };
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn read_range_valid() {
+    let file_path = "./target/__test/ut/file_test/read_range/read_range_valid/file.txt";
+    write_text_file(file_path, "0123456789").unwrap();
+
+    assert_eq!(read_range(file_path, 0, 3).unwrap(), "012".as_bytes());
+    assert_eq!(read_range(file_path, 7, 3).unwrap(), "789".as_bytes());
+    assert_eq!(read_range(file_path, 8, 10).unwrap(), "89".as_bytes());
+    assert!(read_range(file_path, 20, 10).unwrap().is_empty());
+    assert!(read_range(file_path, 2, 0).unwrap().is_empty());
+}
+
+#[test]
+fn read_range_large_file() {
+    let file_path = "./target/__test/ut/file_test/read_range/read_range_large_file/file.bin";
+    let data: Vec<u8> = (0..300 * 1024).map(|index| (index % 251) as u8).collect();
+    write_file(file_path, &data).unwrap();
+
+    let range = read_range(file_path, 1000, 200 * 1024).unwrap();
+
+    assert_eq!(range, &data[1000..1000 + 200 * 1024]);
+}
+
+#[test]
+fn read_range_huge_length() {
+    let file_path = "./target/__test/ut/file_test/read_range/read_range_huge_length/file.txt";
+    write_text_file(file_path, "0123456789").unwrap();
+
+    assert_eq!(read_range(file_path, 5, usize::MAX).unwrap(), "56789".as_bytes());
+}
+
+#[test]
+fn read_range_not_found() {
+    let result = read_range(
+        "./target/__test/ut/file_test/read_range/read_range_not_found/file.txt",
+        0,
+        10,
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn read_range_directory() {
+    let directory_path = "./target/__test/ut/file_test/read_range/read_range_directory";
+    directory::create(directory_path).unwrap();
+
+    let result = read_range(directory_path, 0, 10);
+
+    match result {
+        Err(FsIOError::NotFile(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+fn read_range_shared_handle_position() {
+    let file_path =
+        "./target/__test/ut/file_test/read_range/read_range_shared_handle_position/file.txt";
+    write_text_file(file_path, "0123456789").unwrap();
+    let mut fd = File::open(file_path).unwrap();
+    let mut buffer = [0; 2];
+    fd.read_exact(&mut buffer).unwrap();
+
+    read_range(file_path, 5, 3).unwrap();
+    write_at(file_path, 0, "ab".as_bytes()).unwrap();
+
+    fd.read_exact(&mut buffer).unwrap();
+    assert_eq!(&buffer, "23".as_bytes());
+}
+
+#[test]
+fn write_at_valid() {
+    let file_path = "./target/__test/ut/file_test/write_at/write_at_valid/file.txt";
+    write_text_file(file_path, "0123456789").unwrap();
+
+    write_at(file_path, 0, "ab".as_bytes()).unwrap();
+    write_at(file_path, 8, "xyz".as_bytes()).unwrap();
+
+    assert_eq!(read_text_file(file_path).unwrap(), "ab234567xyz");
+}
+
+#[test]
+fn write_at_not_found() {
+    let file_path = "./target/__test/ut/file_test/write_at/write_at_not_found/file.txt";
+
+    let result = write_at(file_path, 0, "ab".as_bytes());
+
+    assert!(result.is_err());
+    assert!(!Path::new(file_path).exists());
+}
+
+#[test]
+fn read_tail_valid() {
+    let file_path = "./target/__test/ut/file_test/read_tail/read_tail_valid/file.txt";
+    write_text_file(file_path, "0123456789").unwrap();
+
+    assert_eq!(read_tail(file_path, 3).unwrap(), "789".as_bytes());
+    assert_eq!(read_tail(file_path, 20).unwrap(), "0123456789".as_bytes());
+    assert!(read_tail(file_path, 0).unwrap().is_empty());
+}
+
+#[test]
+fn read_tail_empty() {
+    let file_path = "./target/__test/ut/file_test/read_tail/read_tail_empty/file.txt";
+    write_text_file(file_path, "").unwrap();
+
+    assert!(read_tail(file_path, 10).unwrap().is_empty());
+}
+
+#[test]
+fn write_at_offset_overflow() {
+    let file_path = "./target/__test/ut/file_test/write_at/write_at_offset_overflow/file.txt";
+    write_text_file(file_path, "").unwrap();
+
+    let result = write_at(file_path, u64::MAX, "data".as_bytes());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn get_position_overflow() {
+    let file_path = Path::new("file.txt");
+
+    assert_eq!(get_position(file_path, 10, 5).unwrap(), 15);
+    assert!(get_position(file_path, u64::MAX, 1).is_err());
+}
+
+#[test]
+#[cfg(not(windows))]
+fn read_tail_fifo() {
+    use std::process::Command;
+
+    let directory = "./target/__test/ut/file_test/read_tail/read_tail_fifo";
+    directory::delete(directory).unwrap();
+    directory::create(directory).unwrap();
+    let file_path = format!("{}/fifo", directory);
+    let status = Command::new("mkfifo").arg(&file_path).status().unwrap();
+    assert!(status.success());
+
+    let result = read_tail(&file_path, 10);
+
+    match result {
+        Err(FsIOError::NotFile(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+fn create_follow_options() -> FollowOptions {
+    let mut options = FollowOptions::new();
+    options.poll_interval = Duration::from_millis(10);
+
+    options
+}
+
+/// Creates a clean test directory and returns the followed file path.
+fn create_follow_file_path(name: &str) -> String {
+    let directory_path = format!("./target/__test/ut/file_test/follow/{}", name);
+    directory::delete(&directory_path).unwrap();
+    directory::create(&directory_path).unwrap();
+
+    format!("{}/file.log", directory_path)
+}
+
+/// Collects the requested amount of lines, stopping the iterator if they do not arrive in time.
+fn collect_follow_lines(lines: Follow, count: usize) -> Vec<String> {
+    let stop_handle = lines.stop_handle();
+    let (sender, receiver) = mpsc::channel();
+    let reader = thread::spawn(move || {
+        let collected: Vec<String> = lines.take(count).map(|line| line.unwrap()).collect();
+        sender.send(()).unwrap_or(());
+        collected
+    });
+
+    if receiver.recv_timeout(Duration::from_secs(10)).is_err() {
+        stop_handle.stop();
+    }
+
+    reader.join().unwrap()
+}
+
+#[test]
+fn follow_appended_lines() {
+    let file_path = create_follow_file_path("follow_appended_lines");
+    write_text_file(&file_path, "old\n").unwrap();
+
+    let lines = follow_with_options(&file_path, &create_follow_options());
+    let writer_file_path = file_path.clone();
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        append_text_file(&writer_file_path, "line1\r\nli").unwrap();
+        thread::sleep(Duration::from_millis(50));
+        append_text_file(&writer_file_path, "ne2\n").unwrap();
+    });
+
+    let lines = collect_follow_lines(lines, 2);
+    writer.join().unwrap();
+
+    assert_eq!(lines, vec!["line1", "line2"]);
+}
+
+#[test]
+fn follow_from_start() {
+    let file_path = create_follow_file_path("follow_from_start");
+    write_text_file(&file_path, "line1\nline2\n").unwrap();
+
+    let mut options = create_follow_options();
+    options.from_start = true;
+    options.crlf = false;
+    let lines = collect_follow_lines(follow_with_options(&file_path, &options), 2);
+
+    assert_eq!(lines, vec!["line1", "line2"]);
+}
+
+#[test]
+fn follow_created_later() {
+    let file_path = create_follow_file_path("follow_created_later");
+
+    let lines = follow_with_options(&file_path, &create_follow_options());
+    let writer_file_path = file_path.clone();
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        write_text_file(&writer_file_path, "line1\n").unwrap();
+    });
+
+    let lines = collect_follow_lines(lines, 1);
+    writer.join().unwrap();
+
+    assert_eq!(lines, vec!["line1"]);
+}
+
+#[test]
+fn follow_truncated() {
+    let file_path = create_follow_file_path("follow_truncated");
+    write_text_file(&file_path, "some old content\n").unwrap();
+
+    let lines = follow_with_options(&file_path, &create_follow_options());
+    let writer_file_path = file_path.clone();
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        write_text_file(&writer_file_path, "new\n").unwrap();
+    });
+
+    let lines = collect_follow_lines(lines, 1);
+    writer.join().unwrap();
+
+    assert_eq!(lines, vec!["new"]);
+}
+
+#[test]
+fn follow_rewritten_same_size() {
+    let file_path = create_follow_file_path("follow_rewritten_same_size");
+    write_text_file(&file_path, "old1\n").unwrap();
+
+    let lines = follow_with_options(&file_path, &create_follow_options());
+    let writer_file_path = file_path.clone();
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        // same size and different content, detected regardless of the modified time
+        write_text_file(&writer_file_path, "new1\n").unwrap();
+    });
+
+    let lines = collect_follow_lines(lines, 1);
+    writer.join().unwrap();
+
+    assert_eq!(lines, vec!["new1"]);
+}
+
+#[test]
+fn follow_rewritten_same_content_not_replayed() {
+    let file_path = create_follow_file_path("follow_rewritten_same_content_not_replayed");
+    write_text_file(&file_path, "old1\n").unwrap();
+
+    let lines = follow_with_options(&file_path, &create_follow_options());
+    let writer_file_path = file_path.clone();
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        write_text_file(&writer_file_path, "old1\n").unwrap();
+        thread::sleep(Duration::from_millis(50));
+        append_text_file(&writer_file_path, "new1\n").unwrap();
+    });
+
+    let lines = collect_follow_lines(lines, 1);
+    writer.join().unwrap();
+
+    assert_eq!(lines, vec!["new1"]);
+}
+
+#[test]
+fn follow_rotated() {
+    let file_path = create_follow_file_path("follow_rotated");
+    let rotated_path = format!("{}.1", &file_path);
+    write_text_file(&file_path, "old\n").unwrap();
+
+    let lines = follow_with_options(&file_path, &create_follow_options());
+    let writer_file_path = file_path.clone();
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        append_text_file(&writer_file_path, "before rotation\nlast").unwrap();
+        rename(&writer_file_path, &rotated_path).unwrap();
+        thread::sleep(Duration::from_millis(50));
+        write_text_file(&writer_file_path, "after rotation with more content\n").unwrap();
+    });
+
+    let lines = collect_follow_lines(lines, 3);
+    writer.join().unwrap();
+
+    assert_eq!(
+        lines,
+        vec!["before rotation", "last", "after rotation with more content"]
+    );
+}
+
+#[test]
+fn follow_stop_handle() {
+    let file_path = create_follow_file_path("follow_stop_handle");
+    write_text_file(&file_path, "old\n").unwrap();
+
+    let lines = follow_with_options(&file_path, &create_follow_options());
+    let stop_handle = lines.stop_handle();
+    let reader = thread::spawn(move || lines.count());
+
+    thread::sleep(Duration::from_millis(50));
+    stop_handle.stop();
+
+    assert_eq!(reader.join().unwrap(), 0);
+}
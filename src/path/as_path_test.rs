@@ -21,7 +21,7 @@ fn as_path_string() {
 #[test]
 fn as_path_path() {
     let path = Path::new("./test/file.txt");
-    let as_path = path.as_path();
+    let as_path = AsPath::as_path(&path);
 
     assert_eq!(path, as_path);
 }
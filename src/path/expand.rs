@@ -0,0 +1,218 @@
+//! # expand
+//!
+//! Home directory and environment variable expansion.
+//!
+
+#[cfg(test)]
+#[path = "./expand_test.rs"]
+mod expand_test;
+
+use crate::error::FsIOError;
+use std::env;
+use std::env::VarError;
+use std::path::PathBuf;
+
+#[cfg(all(not(windows), feature = "users"))]
+use users::os::unix::UserExt;
+#[cfg(all(not(windows), feature = "users"))]
+use users::{get_current_uid, get_user_by_name, get_user_by_uid};
+
+#[cfg(windows)]
+const HOME_VARIABLE: &str = "USERPROFILE";
+
+#[cfg(not(windows))]
+const HOME_VARIABLE: &str = "HOME";
+
+#[cfg(all(not(windows), feature = "users"))]
+fn get_current_user_home_directory() -> Option<PathBuf> {
+    get_user_by_uid(get_current_uid()).map(|user| user.home_dir().to_path_buf())
+}
+
+#[cfg(not(all(not(windows), feature = "users")))]
+fn get_current_user_home_directory() -> Option<PathBuf> {
+    None
+}
+
+#[cfg(all(not(windows), feature = "users"))]
+fn get_user_home_directory(name: &str) -> Option<PathBuf> {
+    get_user_by_name(name).map(|user| user.home_dir().to_path_buf())
+}
+
+#[cfg(not(all(not(windows), feature = "users")))]
+fn get_user_home_directory(_name: &str) -> Option<PathBuf> {
+    None
+}
+
+/// Returns the current user home directory.
+pub(crate) fn get_home_directory() -> Option<PathBuf> {
+    match env::var_os(HOME_VARIABLE) {
+        Some(value) if !value.is_empty() => Some(PathBuf::from(value)),
+        _ => get_current_user_home_directory(),
+    }
+}
+
+fn is_separator(character: char) -> bool {
+    character == '/' || (cfg!(windows) && character == '\\')
+}
+
+fn expand_tilde(value: &str, strict: bool) -> Result<String, FsIOError> {
+    let rest = match value.strip_prefix('~') {
+        Some(rest) => rest,
+        None => return Ok(value.to_string()),
+    };
+
+    let name_length = rest.find(is_separator).unwrap_or(rest.len());
+    let (name, suffix) = rest.split_at(name_length);
+
+    let home = if name.is_empty() {
+        get_home_directory()
+    } else {
+        get_user_home_directory(name)
+    };
+
+    match home {
+        Some(home) => match home.to_str() {
+            Some(home) => Ok(format!("{}{}", home, suffix)),
+            None => Err(FsIOError::InvalidPath(
+                    format!("Home directory: {:?} is not valid unicode.", &home).to_string(),
+                ),
+            ),
+        },
+        None => {
+            if strict {
+                Err(FsIOError::InvalidPath(
+                        format!("Unable to find home directory for: ~{}", name).to_string(),
+                    ),
+                )
+            } else {
+                Ok(value.to_string())
+            }
+        }
+    }
+}
+
+fn is_name_start(character: char) -> bool {
+    character == '_' || character.is_ascii_alphabetic()
+}
+
+fn is_name_part(character: char) -> bool {
+    character == '_' || character.is_ascii_alphanumeric()
+}
+
+/// Returns the variable value or none if not defined.
+/// An error is returned for values which are not valid unicode instead of corrupting them.
+fn read_variable(name: &str) -> Result<Option<String>, FsIOError> {
+    match env::var(name) {
+        Ok(value) => Ok(Some(value)),
+        Err(VarError::NotPresent) => Ok(None),
+        Err(VarError::NotUnicode(_)) => Err(FsIOError::InvalidPath(
+                format!("Environment variable: {} is not valid unicode.", name).to_string(),
+            ),
+        ),
+    }
+}
+
+fn get_variable(name: &str, strict: bool) -> Result<String, FsIOError> {
+    match read_variable(name)? {
+        Some(value) => Ok(value),
+        None => {
+            if strict {
+                Err(FsIOError::UndefinedVariable(
+                        format!("Environment variable: {} is not defined.", name).to_string(),
+                    ),
+                )
+            } else {
+                Ok("".to_string())
+            }
+        }
+    }
+}
+
+fn expand_variables(value: &str, strict: bool) -> Result<String, FsIOError> {
+    let chars: Vec<char> = value.chars().collect();
+    let mut output = String::new();
+
+    let mut index = 0;
+    while index < chars.len() {
+        let character = chars[index];
+        index = index + 1;
+
+        if character != '$' || index == chars.len() {
+            output.push(character);
+            continue;
+        }
+
+        if chars[index] == '{' {
+            // nested references (for example in default values) are skipped by counting braces
+            let mut depth = 0;
+            let mut end = None;
+            for (offset, value) in chars[index..].iter().enumerate() {
+                match value {
+                    '{' => depth = depth + 1,
+                    '}' => {
+                        depth = depth - 1;
+                        if depth == 0 {
+                            end = Some(index + offset);
+                            break;
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            let end = match end {
+                Some(end) => end,
+                None => {
+                    // unterminated reference, keep as is
+                    output.push(character);
+                    continue;
+                }
+            };
+
+            let reference: String = chars[index + 1..end].iter().collect();
+            index = end + 1;
+
+            match reference.find(":-") {
+                Some(separator) => {
+                    let name = &reference[..separator];
+                    let default_value = &reference[separator + 2..];
+
+                    match read_variable(name)? {
+                        Some(ref variable_value) if !variable_value.is_empty() => {
+                            output.push_str(variable_value)
+                        }
+                        _ => output.push_str(&expand_variables(default_value, strict)?),
+                    }
+                }
+                None => output.push_str(&get_variable(&reference, strict)?),
+            }
+        } else if is_name_start(chars[index]) {
+            let start = index;
+            while index < chars.len() && is_name_part(chars[index]) {
+                index = index + 1;
+            }
+
+            let name: String = chars[start..index].iter().collect();
+            output.push_str(&get_variable(&name, strict)?);
+        } else {
+            output.push(character);
+        }
+    }
+
+    Ok(output)
+}
+
+pub(crate) fn expand(value: &str, strict: bool) -> Result<String, FsIOError> {
+    // only the rest of the value is expanded, so references in the home directory are kept
+    let tilde_length = if value.starts_with('~') {
+        value.find(is_separator).unwrap_or(value.len())
+    } else {
+        0
+    };
+    let (tilde, rest) = value.split_at(tilde_length);
+
+    let home = expand_tilde(tilde, strict)?;
+    let expanded = expand_variables(rest, strict)?;
+
+    Ok(format!("{}{}", home, expanded))
+}
@@ -0,0 +1,135 @@
+use super::*;
+
+#[test]
+fn expand_variables_none() {
+    let expanded = expand_variables("./a/b", true).unwrap();
+
+    assert_eq!(expanded, "./a/b");
+}
+
+#[test]
+fn expand_variables_simple() {
+    env::set_var("FSIO_TEST_EXPAND_SIMPLE", "value");
+
+    let expanded = expand_variables("./$FSIO_TEST_EXPAND_SIMPLE/b", true).unwrap();
+
+    assert_eq!(expanded, "./value/b");
+}
+
+#[test]
+fn expand_variables_braces() {
+    env::set_var("FSIO_TEST_EXPAND_BRACES", "value");
+
+    let expanded = expand_variables("./${FSIO_TEST_EXPAND_BRACES}b", true).unwrap();
+
+    assert_eq!(expanded, "./valueb");
+}
+
+#[test]
+fn expand_variables_default() {
+    env::set_var("FSIO_TEST_EXPAND_DEFAULT_EMPTY", "");
+    env::set_var("FSIO_TEST_EXPAND_DEFAULT_NESTED", "nested");
+
+    let mut expanded =
+        expand_variables("${FSIO_TEST_EXPAND_DEFAULT_UNDEFINED:-out}", true).unwrap();
+    assert_eq!(expanded, "out");
+
+    expanded = expand_variables("${FSIO_TEST_EXPAND_DEFAULT_EMPTY:-out}", true).unwrap();
+    assert_eq!(expanded, "out");
+
+    expanded = expand_variables(
+        "${FSIO_TEST_EXPAND_DEFAULT_UNDEFINED:-$FSIO_TEST_EXPAND_DEFAULT_NESTED}",
+        true,
+    )
+    .unwrap();
+    assert_eq!(expanded, "nested");
+}
+
+#[test]
+fn expand_variables_nested_default() {
+    env::set_var("FSIO_TEST_EXPAND_NESTED_DEFAULT", "nested");
+
+    let mut expanded = expand_variables(
+        "./${FSIO_TEST_EXPAND_NESTED_UNDEFINED:-${FSIO_TEST_EXPAND_NESTED_DEFAULT}}/b",
+        true,
+    )
+    .unwrap();
+    assert_eq!(expanded, "./nested/b");
+
+    expanded = expand_variables(
+        "${FSIO_TEST_EXPAND_NESTED_UNDEFINED:-${FSIO_TEST_EXPAND_NESTED_OTHER:-a}/b}/c",
+        true,
+    )
+    .unwrap();
+    assert_eq!(expanded, "a/b/c");
+}
+
+#[test]
+#[cfg(not(windows))]
+fn expand_variables_not_unicode() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    env::set_var("FSIO_TEST_EXPAND_NOT_UNICODE", OsStr::from_bytes(b"a\xffb"));
+
+    let result = expand_variables("./$FSIO_TEST_EXPAND_NOT_UNICODE", false);
+
+    match result {
+        Err(FsIOError::InvalidPath(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+fn expand_variables_undefined() {
+    let expanded = expand_variables("a/$FSIO_TEST_EXPAND_UNDEFINED/b", false).unwrap();
+    assert_eq!(expanded, "a//b");
+
+    let result = expand_variables("a/$FSIO_TEST_EXPAND_UNDEFINED/b", true);
+    assert!(result.is_err());
+}
+
+#[test]
+fn expand_variables_literal_dollar() {
+    let expanded = expand_variables("a$/$1/${b/c$", true).unwrap();
+
+    assert_eq!(expanded, "a$/$1/${b/c$");
+}
+
+#[test]
+fn expand_tilde_none() {
+    let expanded = expand_tilde("a/~/b", true).unwrap();
+
+    assert_eq!(expanded, "a/~/b");
+}
+
+#[test]
+fn expand_tilde_home() {
+    let home = get_home_directory().unwrap();
+
+    let mut expanded = expand_tilde("~", true).unwrap();
+    assert_eq!(expanded, home.to_string_lossy());
+
+    expanded = expand_tilde("~/cache", true).unwrap();
+    assert_eq!(expanded, format!("{}/cache", home.to_string_lossy()));
+}
+
+#[test]
+fn expand_tilde_unknown_user() {
+    let expanded = expand_tilde("~fsio_unknown_user/a", false).unwrap();
+    assert_eq!(expanded, "~fsio_unknown_user/a");
+
+    let result = expand_tilde("~fsio_unknown_user/a", true);
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(all(not(windows), feature = "users"))]
+fn expand_tilde_user() {
+    let user = get_user_by_uid(get_current_uid()).unwrap();
+    let name = user.name().to_string_lossy();
+
+    let expanded = expand_tilde(&format!("~{}/a", name), true).unwrap();
+
+    assert_eq!(expanded, format!("{}/a", user.home_dir().to_string_lossy()));
+}
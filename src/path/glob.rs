@@ -0,0 +1,328 @@
+//! # glob
+//!
+//! Glob pattern matching.
+//!
+
+#[cfg(test)]
+#[path = "./glob_test.rs"]
+mod glob_test;
+
+use crate::directory::{walk_with_options, WalkOptions};
+use crate::error::FsIOError;
+use crate::path::as_path::AsPath;
+use crate::path::from_path::FromPath;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+struct Pattern {
+    negated: bool,
+    alternatives: Vec<Vec<String>>,
+}
+
+impl Pattern {
+    fn new(pattern: &str) -> Pattern {
+        let (negated, pattern) = match pattern.strip_prefix('!') {
+            Some(value) => (true, value),
+            None => (false, pattern),
+        };
+
+        let alternatives = expand_braces(&normalize_separators(pattern))
+            .iter()
+            .map(|alternative| split_segments(alternative))
+            .collect();
+
+        Pattern {
+            negated,
+            alternatives,
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let path_string = normalize_separators(&path.to_string_lossy());
+        let path_segments = split_segments(&path_string);
+        let path_segments: Vec<&str> = path_segments.iter().map(|value| value.as_str()).collect();
+
+        let matched = self
+            .alternatives
+            .iter()
+            .any(|segments| match_segments(segments, &path_segments));
+
+        matched != self.negated
+    }
+}
+
+#[cfg(windows)]
+fn normalize_separators(value: &str) -> String {
+    value.replace('\\', "/")
+}
+
+#[cfg(not(windows))]
+fn normalize_separators(value: &str) -> String {
+    value.to_string()
+}
+
+/// Splits to path segments, ignoring empty and current directory segments.
+/// Absolute paths start with an empty segment.
+fn split_segments(value: &str) -> Vec<String> {
+    let mut segments = vec![];
+    if value.starts_with('/') {
+        segments.push("".to_string());
+    }
+
+    for segment in value.split('/') {
+        if !segment.is_empty() && segment != "." {
+            segments.push(segment.to_string());
+        }
+    }
+
+    segments
+}
+
+/// Expands brace alternations (including nested ones) into separate patterns.
+/// Unbalanced braces are treated as literal characters.
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let chars: Vec<char> = pattern.chars().collect();
+
+    let start = match chars.iter().position(|value| *value == '{') {
+        Some(index) => index,
+        None => return vec![pattern.to_string()],
+    };
+
+    let mut depth = 0;
+    let mut end = None;
+    let mut separators = vec![];
+    for index in start..chars.len() {
+        match chars[index] {
+            '{' => depth = depth + 1,
+            '}' => {
+                depth = depth - 1;
+                if depth == 0 {
+                    end = Some(index);
+                    break;
+                }
+            }
+            ',' if depth == 1 => separators.push(index),
+            _ => (),
+        }
+    }
+
+    let end = match end {
+        Some(index) => index,
+        None => return vec![pattern.to_string()],
+    };
+
+    let prefix: String = chars[..start].iter().collect();
+    let suffix: String = chars[end + 1..].iter().collect();
+
+    let mut bounds = vec![start];
+    bounds.extend(separators);
+    bounds.push(end);
+
+    let mut expanded = vec![];
+    for window in bounds.windows(2) {
+        let option: String = chars[window[0] + 1..window[1]].iter().collect();
+        let alternative = format!("{}{}{}", prefix, option, suffix);
+        expanded.extend(expand_braces(&alternative));
+    }
+
+    expanded
+}
+
+fn match_segments(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.first() {
+        None => path.is_empty(),
+        Some(segment) if segment == "**" => {
+            (0..=path.len()).any(|index| match_segments(&pattern[1..], &path[index..]))
+        }
+        Some(segment) => match path.first() {
+            Some(path_segment) => {
+                match_segment(segment, path_segment) && match_segments(&pattern[1..], &path[1..])
+            }
+            None => false,
+        },
+    }
+}
+
+fn match_segment(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+
+    match_chars(&pattern, &value)
+}
+
+/// Matches the value using backtracking to the last `*` only, which keeps the matching
+/// linear in the number of stars instead of exponential.
+fn match_chars(pattern: &[char], value: &[char]) -> bool {
+    let mut pattern_index = 0;
+    let mut value_index = 0;
+    // the pattern index after the last star and the value index it currently matches up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while pattern_index < pattern.len() || value_index < value.len() {
+        let length = match pattern.get(pattern_index) {
+            Some('*') => {
+                backtrack = Some((pattern_index + 1, value_index));
+                pattern_index = pattern_index + 1;
+                continue;
+            }
+            Some('?') if value_index < value.len() => Some(1),
+            Some('[') => match match_class(&pattern[pattern_index..], value.get(value_index)) {
+                Some((true, length)) => Some(length),
+                Some((false, _)) => None,
+                // unterminated class, treat as literal
+                None if value.get(value_index) == Some(&'[') => Some(1),
+                None => None,
+            },
+            Some(character) if value.get(value_index) == Some(character) => Some(1),
+            _ => None,
+        };
+
+        match length {
+            Some(length) => {
+                pattern_index = pattern_index + length;
+                value_index = value_index + 1;
+            }
+            None => match backtrack {
+                // let the last star consume one more character and retry
+                Some((star_pattern_index, star_value_index)) if star_value_index < value.len() => {
+                    backtrack = Some((star_pattern_index, star_value_index + 1));
+                    pattern_index = star_pattern_index;
+                    value_index = star_value_index + 1;
+                }
+                _ => return false,
+            },
+        }
+    }
+
+    true
+}
+
+/// Matches a character class starting at the beginning of the pattern.
+/// Returns the match result and the class length, or none if the class is not terminated.
+fn match_class(pattern: &[char], value: Option<&char>) -> Option<(bool, usize)> {
+    let mut index = 1;
+    let negated = match pattern.get(index) {
+        Some('!') | Some('^') => {
+            index = index + 1;
+            true
+        }
+        _ => false,
+    };
+
+    let mut matched = false;
+    let mut first = true;
+    loop {
+        let current = *pattern.get(index)?;
+        if current == ']' && !first {
+            break;
+        }
+        first = false;
+
+        let range_end = match (pattern.get(index + 1), pattern.get(index + 2)) {
+            (Some('-'), Some(end)) if *end != ']' => Some(*end),
+            _ => None,
+        };
+
+        if let Some(character) = value {
+            matched = matched
+                || match range_end {
+                    Some(end) => current <= *character && *character <= end,
+                    None => current == *character,
+                };
+        }
+
+        index = index + if range_end.is_some() { 3 } else { 1 };
+    }
+
+    Some((value.is_some() && matched != negated, index + 1))
+}
+
+fn has_wildcards(segment: &str) -> bool {
+    segment.contains(|character| match character {
+        '*' | '?' | '[' => true,
+        _ => false,
+    })
+}
+
+pub(crate) fn matches<T: AsPath + ?Sized>(pattern: &str, path: &T) -> bool {
+    Pattern::new(pattern).matches(path.as_path())
+}
+
+pub(crate) fn find<T: FromPath>(pattern: &str) -> Result<Vec<T>, FsIOError> {
+    let compiled = Pattern::new(pattern);
+
+    // a negated pattern matches every path outside of it, which requires walking everything
+    if compiled.negated {
+        return Err(FsIOError::InvalidPath(
+                format!("Negated glob pattern: {} can not be searched.", pattern).to_string(),
+            ),
+        );
+    }
+
+    // search roots are the literal leading segments of every alternative
+    let mut roots = BTreeSet::new();
+    for segments in &compiled.alternatives {
+        let literal_count = segments
+            .iter()
+            .take_while(|segment| !has_wildcards(segment))
+            .count();
+
+        let mut root = PathBuf::new();
+        for segment in &segments[..literal_count] {
+            if segment.is_empty() {
+                root.push("/");
+            } else {
+                root.push(segment);
+            }
+        }
+
+        let max_depth = if segments.iter().any(|segment| segment == "**") {
+            None
+        } else {
+            Some(segments.len() - literal_count)
+        };
+
+        roots.insert((root, max_depth));
+    }
+
+    let mut paths = BTreeSet::new();
+    for (root, max_depth) in roots {
+        let walk_root = if root.as_os_str().is_empty() {
+            PathBuf::from(".")
+        } else {
+            root.clone()
+        };
+
+        if !walk_root.exists() {
+            continue;
+        }
+
+        let mut options = WalkOptions::new();
+        options.max_depth = max_depth;
+        options.sort = true;
+
+        for entry in walk_with_options(&walk_root, &options) {
+            // unreadable entries (for example directories without permissions) are skipped
+            let entry = match entry {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+
+            let path = if root.as_os_str().is_empty() {
+                match entry.path().strip_prefix(".") {
+                    Ok(value) => value.to_path_buf(),
+                    Err(_) => entry.path().to_path_buf(),
+                }
+            } else {
+                entry.path().to_path_buf()
+            };
+
+            if !path.as_os_str().is_empty() && compiled.matches(&path) {
+                paths.insert(path);
+            }
+        }
+    }
+
+    Ok(paths.iter().map(|path| FromPath::from_path(path)).collect())
+}
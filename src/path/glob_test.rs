@@ -0,0 +1,138 @@
+use super::*;
+
+#[test]
+fn split_segments_relative() {
+    let segments = split_segments("./src//path/./mod.rs");
+
+    assert_eq!(segments, vec!["src", "path", "mod.rs"]);
+}
+
+#[test]
+fn split_segments_absolute() {
+    let segments = split_segments("/src/mod.rs");
+
+    assert_eq!(segments, vec!["", "src", "mod.rs"]);
+}
+
+#[test]
+fn expand_braces_none() {
+    let expanded = expand_braces("src/*.rs");
+
+    assert_eq!(expanded, vec!["src/*.rs"]);
+}
+
+#[test]
+fn expand_braces_multiple() {
+    let expanded = expand_braces("{src,tests}/*.{rs,md}");
+
+    assert_eq!(
+        expanded,
+        vec!["src/*.rs", "src/*.md", "tests/*.rs", "tests/*.md"]
+    );
+}
+
+#[test]
+fn expand_braces_nested() {
+    let expanded = expand_braces("a{b,c{d,e}}f");
+
+    assert_eq!(expanded, vec!["abf", "acdf", "acef"]);
+}
+
+#[test]
+fn expand_braces_unbalanced() {
+    let expanded = expand_braces("a{b,c");
+
+    assert_eq!(expanded, vec!["a{b,c"]);
+}
+
+#[test]
+fn match_segment_literal() {
+    assert!(match_segment("mod.rs", "mod.rs"));
+    assert!(!match_segment("mod.rs", "mod.rs2"));
+}
+
+#[test]
+fn match_segment_wildcards() {
+    assert!(match_segment("*.rs", "mod.rs"));
+    assert!(match_segment("*", ""));
+    assert!(match_segment("m?d.*", "mod.rs"));
+    assert!(!match_segment("m?d.*", "md.rs"));
+    assert!(match_segment("*_test*", "mod_test.rs"));
+}
+
+#[test]
+fn match_segment_class() {
+    assert!(match_segment("[abc].rs", "b.rs"));
+    assert!(!match_segment("[abc].rs", "d.rs"));
+    assert!(match_segment("[a-z]1", "x1"));
+    assert!(!match_segment("[a-z]1", "X1"));
+    assert!(match_segment("[!a-z]1", "X1"));
+    assert!(!match_segment("[^a-z]1", "x1"));
+    assert!(match_segment("[]]", "]"));
+    assert!(match_segment("[a-]", "-"));
+}
+
+#[test]
+fn match_segment_unterminated_class() {
+    assert!(match_segment("[ab", "[ab"));
+    assert!(!match_segment("[ab", "a"));
+}
+
+#[test]
+fn matches_double_star() {
+    assert!(matches("src/**/*.rs", "src/lib.rs"));
+    assert!(matches("src/**/*.rs", "src/path/mod.rs"));
+    assert!(matches("**", "src/path/mod.rs"));
+    assert!(!matches("src/**/*.rs", "tests/file_test.rs"));
+    assert!(!matches("src/*.rs", "src/path/mod.rs"));
+}
+
+#[test]
+fn matches_absolute() {
+    assert!(matches("/tmp/*", "/tmp/file.txt"));
+    assert!(!matches("/tmp/*", "tmp/file.txt"));
+}
+
+#[test]
+fn matches_negated() {
+    assert!(matches("!*.rs", "file.txt"));
+    assert!(!matches("!*.rs", "file.rs"));
+}
+
+#[test]
+fn match_segment_many_stars() {
+    let value = "a".repeat(100);
+
+    assert!(!match_segment("*a*a*a*a*a*a*a*a*a*a*a*a*a*a*b", &value));
+    assert!(match_segment("*a*a*a*a*a*a*a*a*a*a*a*a*a*a*", &value));
+}
+
+#[test]
+fn find_negated() {
+    let result: Result<Vec<String>, FsIOError> = find("!*.rs");
+
+    match result {
+        Err(FsIOError::InvalidPath(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+#[cfg(not(windows))]
+fn find_unreadable_directory() {
+    use std::fs::{set_permissions, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    let root = "./target/__test/ut/glob_test/find_unreadable_directory";
+    crate::directory::delete(root).unwrap();
+    crate::file::write_text_file(&format!("{}/file.txt", root), "").unwrap();
+    crate::file::write_text_file(&format!("{}/locked/file.txt", root), "").unwrap();
+    let locked = format!("{}/locked", root);
+    set_permissions(&locked, Permissions::from_mode(0o000)).unwrap();
+
+    let result: Result<Vec<String>, FsIOError> = find(&format!("{}/**/*.txt", root));
+
+    set_permissions(&locked, Permissions::from_mode(0o755)).unwrap();
+    let paths = result.unwrap();
+    assert!(paths.contains(&format!("{}/file.txt", &root[2..])));
+}
@@ -5,10 +5,18 @@
 
 pub mod as_path;
 pub mod from_path;
+pub mod xdg;
+
+mod expand;
+mod glob;
+mod which;
 
 #[cfg(feature = "temp-path")]
 mod temp_path;
 
+#[cfg(feature = "temp-path")]
+pub use temp_path::{CleanupReport, TempDir, TempFile, TempPathBuilder};
+
 #[cfg(test)]
 #[path = "./mod_test.rs"]
 mod mod_test;
@@ -17,6 +25,11 @@ use crate::error::FsIOError;
 use as_path::AsPath;
 use from_path::FromPath;
 use std::fs;
+#[cfg(feature = "temp-path")]
+use std::fs::File;
+#[cfg(feature = "temp-path")]
+use std::time::Duration;
+use std::path::{Component, Path, PathBuf};
 use std::time::SystemTime;
 
 /// Returns a canonicalized string from the provided path value.
@@ -84,6 +97,413 @@ pub fn canonicalize_or<T: AsPath + ?Sized>(path: &T, or_value: &str) -> String {
     }
 }
 
+/// Returns the normalized path, without accessing the file system.
+/// Current directory (`.`) components and duplicate separators are removed and parent
+/// directory (`..`) components are resolved lexically.
+/// Absolute paths never go above the root, while leading `..` components are kept for relative
+/// paths.
+/// Unlike [canonicalize_as_string](fn.canonicalize_as_string.html), the path does not need to
+/// exist and symbolic links are not resolved.
+///
+/// # Arguments
+///
+/// * `path` - The path value
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let normalized: PathBuf = path::normalize("./src//path/../file.rs");
+///     assert_eq!(normalized, PathBuf::from("src/file.rs"));
+///
+///     let normalized: PathBuf = path::normalize("../a/./b/../../c");
+///     assert_eq!(normalized, PathBuf::from("../c"));
+/// }
+/// ```
+pub fn normalize<T: AsPath + ?Sized, R: FromPath>(path: &T) -> R {
+    let mut components = vec![];
+
+    for component in path.as_path().components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir => match components.last() {
+                Some(Component::Normal(_)) => {
+                    components.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => (),
+                _ => components.push(component),
+            },
+            _ => components.push(component),
+        }
+    }
+
+    let normalized: PathBuf = if components.is_empty() {
+        PathBuf::from(".")
+    } else {
+        components.iter().collect()
+    };
+
+    FromPath::from_path(&normalized)
+}
+
+/// Returns the relative path leading from the base path to the provided path.
+/// Both paths are [normalized](fn.normalize.html) and compared lexically, without accessing the
+/// file system, therefore symbolic links are not resolved.
+/// An error is returned if the paths are not both absolute or both relative, or if the base path
+/// goes above the path common ancestor (for example `../dir`).
+///
+/// # Arguments
+///
+/// * `path` - The path value
+/// * `base` - The base path value
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let relative: PathBuf = path::relative_to("/usr/share/doc", "/usr/lib/rust").unwrap();
+///     assert_eq!(relative, PathBuf::from("../../share/doc"));
+///
+///     let relative: PathBuf = path::relative_to("./src/path/mod.rs", "src").unwrap();
+///     assert_eq!(relative, PathBuf::from("path/mod.rs"));
+/// }
+/// ```
+pub fn relative_to<P: AsPath + ?Sized, B: AsPath + ?Sized, R: FromPath>(
+    path: &P,
+    base: &B,
+) -> Result<R, FsIOError> {
+    let path_obj: PathBuf = normalize(path);
+    let base_obj: PathBuf = normalize(base);
+
+    let path_components: Vec<Component> = path_obj
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect();
+    let base_components: Vec<Component> = base_obj
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect();
+
+    if path_obj.has_root() != base_obj.has_root() {
+        return Err(FsIOError::InvalidPath(
+                format!(
+                    "Unable to get relative path from: {:?} to: {:?}, only one path is absolute.",
+                    &base_obj, &path_obj
+                )
+                .to_string(),
+            ),
+        );
+    }
+
+    let common_count = path_components
+        .iter()
+        .zip(base_components.iter())
+        .take_while(|(path_component, base_component)| path_component == base_component)
+        .count();
+
+    let mut relative = PathBuf::new();
+    for component in &base_components[common_count..] {
+        match component {
+            Component::Normal(_) => relative.push(".."),
+            _ => {
+                return Err(FsIOError::InvalidPath(
+                        format!(
+                            "Unable to get relative path from: {:?} to: {:?}.",
+                            &base_obj, &path_obj
+                        )
+                        .to_string(),
+                    ),
+                )
+            }
+        }
+    }
+
+    for component in &path_components[common_count..] {
+        relative.push(component);
+    }
+
+    if relative.as_os_str().is_empty() {
+        relative.push(".");
+    }
+
+    Ok(FromPath::from_path(&relative))
+}
+
+/// Returns the relative path leading from the base path to the provided path, after both
+/// paths are canonicalized.
+/// Unlike [relative_to](fn.relative_to.html), both paths must exist and symbolic links are
+/// resolved.
+///
+/// # Arguments
+///
+/// * `path` - The path value
+/// * `base` - The base path value
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let relative: PathBuf = path::canonical_relative_to("./src/path/mod.rs", "./src/../tests").unwrap();
+///
+///     assert_eq!(relative, PathBuf::from("../src/path/mod.rs"));
+/// }
+/// ```
+pub fn canonical_relative_to<P: AsPath + ?Sized, B: AsPath + ?Sized, R: FromPath>(
+    path: &P,
+    base: &B,
+) -> Result<R, FsIOError> {
+    let canonicalize_path = |path: &Path| match path.canonicalize() {
+        Ok(path_obj) => Ok(path_obj),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to canonicalize path: {:?}", &path).to_string(),
+                Some(error),
+            ),
+        ),
+    };
+
+    let path_obj = canonicalize_path(path.as_path())?;
+    let base_obj = canonicalize_path(base.as_path())?;
+
+    relative_to(&path_obj, &base_obj)
+}
+
+/// Returns the path with a leading `~` or `~user` replaced with the relevant home directory and
+/// any `$VAR`, `${VAR}` and `${VAR:-default}` environment variable references expanded.
+/// Undefined variables are replaced with an empty string and unknown home directories are kept
+/// as is. Use [expand_strict](fn.expand_strict.html) to get an error instead.
+/// Paths which are not valid unicode are returned as is.
+///
+/// The current user home directory is taken from the `HOME` (`USERPROFILE` on windows)
+/// environment variable. Resolving other users home directories (`~user`) requires the
+/// **users** feature (also enabled by the **temp-path** feature) and is not supported on windows.
+///
+/// # Arguments
+///
+/// * `path` - The path value
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::env;
+///
+/// fn main() {
+///     env::set_var("FSIO_DOC_EXPAND", "project");
+///
+///     let expanded: String = path::expand("./cache/${FSIO_DOC_EXPAND}/${FSIO_DOC_UNDEFINED:-out}");
+///
+///     assert_eq!(expanded, "./cache/project/out");
+/// }
+/// ```
+pub fn expand<T: AsPath + ?Sized, R: FromPath>(path: &T) -> R {
+    let value = match path.as_path().to_str() {
+        Some(value) => value,
+        None => return FromPath::from_path(path.as_path()),
+    };
+
+    match expand::expand(value, false) {
+        Ok(expanded) => FromPath::from_path(Path::new(&expanded)),
+        Err(_) => FromPath::from_path(path.as_path()),
+    }
+}
+
+/// Returns the path with home directory and environment variable references expanded, same
+/// as [expand](fn.expand.html).
+/// An error is returned for undefined variables (without a default value), unknown
+/// home directories and paths or values which are not valid unicode.
+///
+/// # Arguments
+///
+/// * `path` - The path value
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::env;
+///
+/// fn main() {
+///     env::set_var("FSIO_DOC_EXPAND_STRICT", "project");
+///
+///     let expanded: String = path::expand_strict("./$FSIO_DOC_EXPAND_STRICT/out").unwrap();
+///     assert_eq!(expanded, "./project/out");
+///
+///     let result: Result<String, _> = path::expand_strict("./$FSIO_DOC_UNDEFINED/out");
+///     assert!(result.is_err());
+/// }
+/// ```
+pub fn expand_strict<T: AsPath + ?Sized, R: FromPath>(path: &T) -> Result<R, FsIOError> {
+    let path_value = path.as_path();
+    let value = match path_value.to_str() {
+        Some(value) => value,
+        None => {
+            return Err(FsIOError::InvalidPath(
+                    format!("Path: {:?} is not valid unicode.", &path_value).to_string(),
+                ),
+            )
+        }
+    };
+
+    let expanded = expand::expand(value, true)?;
+
+    Ok(FromPath::from_path(Path::new(&expanded)))
+}
+
+/// Joins the untrusted relative path to the base path, ensuring the result stays inside the
+/// base path.
+/// An error is returned if the untrusted path is absolute (or has a windows prefix) or if its
+/// parent directory (`..`) components lead outside of the base path.
+/// The check is lexical and does not access the file system, use
+/// [join_within_checked](fn.join_within_checked.html) to also validate symbolic links.
+///
+/// # Arguments
+///
+/// * `base` - The base path value
+/// * `untrusted` - The untrusted relative path value
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let joined: PathBuf = path::join_within("/var/data", "./a/../b/file.txt").unwrap();
+///     assert_eq!(joined, PathBuf::from("/var/data/b/file.txt"));
+///
+///     let result: Result<PathBuf, _> = path::join_within("/var/data", "a/../../etc/passwd");
+///     assert!(result.is_err());
+/// }
+/// ```
+pub fn join_within<B: AsPath + ?Sized, U: AsPath + ?Sized, R: FromPath>(
+    base: &B,
+    untrusted: &U,
+) -> Result<R, FsIOError> {
+    let untrusted_path = untrusted.as_path();
+
+    let mut components = vec![];
+    for component in untrusted_path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir => {
+                return Err(FsIOError::PathOutsideBase(
+                        format!("Path: {:?} is absolute.", &untrusted_path).to_string(),
+                    ),
+                )
+            }
+            Component::CurDir => (),
+            Component::ParentDir => {
+                if components.pop().is_none() {
+                    return Err(FsIOError::PathOutsideBase(
+                            format!(
+                                "Path: {:?} leads outside of: {:?}",
+                                &untrusted_path,
+                                base.as_path()
+                            )
+                            .to_string(),
+                        ),
+                    );
+                }
+            }
+            Component::Normal(name) => components.push(name),
+        }
+    }
+
+    let mut joined = base.as_path().to_path_buf();
+    for name in components {
+        joined.push(name);
+    }
+
+    Ok(FromPath::from_path(&joined))
+}
+
+/// Joins the untrusted relative path to the base path, same as [join_within](fn.join_within.html),
+/// while also validating that none of the existing path components is a symbolic link resolving
+/// outside of the base path.
+/// The base path must exist.
+///
+/// # Arguments
+///
+/// * `base` - The base path value
+/// * `untrusted` - The untrusted relative path value
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let joined: PathBuf = path::join_within_checked("./src", "path/mod.rs").unwrap();
+///
+///     assert_eq!(joined, PathBuf::from("./src/path/mod.rs"));
+/// }
+/// ```
+pub fn join_within_checked<B: AsPath + ?Sized, U: AsPath + ?Sized, R: FromPath>(
+    base: &B,
+    untrusted: &U,
+) -> Result<R, FsIOError> {
+    let joined: PathBuf = join_within(base, untrusted)?;
+
+    let base_path = base.as_path();
+    let canonical_base = match base_path.canonicalize() {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to canonicalize path: {:?}", &base_path).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    let relative = match joined.strip_prefix(base_path) {
+        Ok(value) => value.to_path_buf(),
+        Err(_) => PathBuf::new(),
+    };
+
+    let mut current = base_path.to_path_buf();
+    for component in relative.components() {
+        current.push(component);
+
+        match current.canonicalize() {
+            Ok(canonical_path) => {
+                if !canonical_path.starts_with(&canonical_base) {
+                    return Err(FsIOError::PathOutsideBase(
+                            format!(
+                                "Path: {:?} resolves to: {:?} which is outside of: {:?}",
+                                &current, &canonical_path, &canonical_base
+                            )
+                            .to_string(),
+                        ),
+                    );
+                }
+            }
+            // the rest of the path does not exist, so it can not contain symbolic links
+            Err(_) => {
+                if fs::symlink_metadata(&current).is_err() {
+                    break;
+                }
+
+                return Err(FsIOError::PathOutsideBase(
+                        format!("Path: {:?} is a broken symbolic link.", &current).to_string(),
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(FromPath::from_path(&joined))
+}
+
 /// Returns the last path component (file name or last directory name).
 ///
 /// # Arguments
@@ -219,3 +639,233 @@ pub fn get_last_modified_time(path: &str) -> Result<u128, FsIOError> {
 pub fn get_temporary_file_path(extension: &str) -> String {
     temp_path::get(extension)
 }
+
+/// Creates a new temporary file and returns its path and the open (read/write) file handle.
+/// Unlike [get_temporary_file_path](fn.get_temporary_file_path.html), the file is created
+/// atomically and fails if the path already exists, in which case a different path is tried.
+/// On unix, the file is created with 0600 permissions and new directories with 0700 permissions.
+///
+/// # Arguments
+///
+/// * `extension` - The file extension
+///
+/// # Feature
+///
+/// This function requires that the **temp-path** feature will be used.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::io::Write;
+/// use std::path::Path;
+///
+/// fn main() {
+///     let (temp_file, mut fd) = path::create_temporary_file("txt").unwrap();
+///     fd.write_all("some content".as_bytes()).unwrap();
+///
+///     assert!(temp_file.ends_with(".txt"));
+///     assert!(Path::new(&temp_file).exists());
+/// }
+/// ```
+#[cfg(feature = "temp-path")]
+pub fn create_temporary_file(extension: &str) -> Result<(String, File), FsIOError> {
+    temp_path::create(extension)
+}
+
+/// Deletes the files and directories located in the default temporary directory (the one used
+/// by [get_temporary_file_path](fn.get_temporary_file_path.html)) which were not modified
+/// for at least the provided duration.
+/// Failing to delete a specific path does not stop the cleanup and is listed in the returned
+/// report.
+///
+/// # Arguments
+///
+/// * `max_age` - The minimum time since the last modification of removed paths
+///
+/// # Feature
+///
+/// This function requires that the **temp-path** feature will be used.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::time::Duration;
+///
+/// fn main() {
+///     let report = path::cleanup_temporary_files(Duration::from_secs(7 * 24 * 60 * 60)).unwrap();
+///
+///     assert!(report.failed.is_empty());
+/// }
+/// ```
+#[cfg(feature = "temp-path")]
+pub fn cleanup_temporary_files(max_age: Duration) -> Result<CleanupReport, FsIOError> {
+    temp_path::cleanup(max_age)
+}
+
+/// Returns true if the provided path matches the glob pattern.
+/// The following syntax is supported:
+///
+/// * `*` - Matches any sequence of characters within a single path component
+/// * `?` - Matches any single character within a single path component
+/// * `**` - Matches any number of path components (including none)
+/// * `[abc]`, `[a-z]` - Matches any of the characters in the class, `[!abc]` negates the class
+/// * `{a,b}` - Matches any of the comma separated alternatives
+/// * `!` - A leading exclamation mark negates the whole pattern
+///
+/// Empty and `.` path components are ignored so `./src/*.rs` and `src/*.rs` are equivalent.
+///
+/// # Arguments
+///
+/// * `pattern` - The glob pattern
+/// * `path` - The path value
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+///
+/// fn main() {
+///     assert!(path::matches_glob("src/**/*.rs", "./src/path/mod.rs"));
+///     assert!(path::matches_glob("*.{md,toml}", "Cargo.toml"));
+///     assert!(!path::matches_glob("!*.rs", "lib.rs"));
+/// }
+/// ```
+pub fn matches_glob<T: AsPath + ?Sized>(pattern: &str, path: &T) -> bool {
+    glob::matches(pattern, path)
+}
+
+/// Returns all the existing paths (files and directories) matching the provided glob pattern,
+/// sorted and without duplicates.
+/// Leading `.` components of the pattern are not kept in the returned paths.
+/// Entries which can not be read (for example directories without permissions) are skipped.
+/// See [matches_glob](fn.matches_glob.html) for the supported pattern syntax, however negated
+/// (`!`) patterns are not supported and result in an InvalidPath error.
+///
+/// # Arguments
+///
+/// * `pattern` - The glob pattern
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let paths: Vec<PathBuf> = path::glob("src/**/*.rs").unwrap();
+///
+///     assert!(paths.contains(&PathBuf::from("src/path/mod.rs")));
+/// }
+/// ```
+pub fn glob<T: FromPath>(pattern: &str) -> Result<Vec<T>, FsIOError> {
+    glob::find(pattern)
+}
+
+/// Returns the first executable file with the provided name found in the directories listed
+/// in the `PATH` environment variable.
+/// On unix, only files with at least one of the execute permission bits are returned, while on
+/// windows the `PATHEXT` extensions are also tried.
+/// Names with a directory component (for example `./run.sh`) are checked directly and not
+/// searched in the `PATH` directories.
+///
+/// # Arguments
+///
+/// * `name` - The executable name
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let cargo: Option<PathBuf> = path::which("cargo");
+///     assert!(cargo.is_some());
+///
+///     let missing: Option<String> = path::which("fsio_unknown_executable");
+///     assert!(missing.is_none());
+/// }
+/// ```
+pub fn which<R: FromPath>(name: &str) -> Option<R> {
+    which_in(name, &which::get_search_paths())
+}
+
+/// Returns all the executable files with the provided name found in the directories listed
+/// in the `PATH` environment variable, in the search order.
+/// See [which](fn.which.html) for more details.
+///
+/// # Arguments
+///
+/// * `name` - The executable name
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let all: Vec<PathBuf> = path::which_all("cargo");
+///
+///     assert!(!all.is_empty());
+/// }
+/// ```
+pub fn which_all<R: FromPath>(name: &str) -> Vec<R> {
+    which_all_in(name, &which::get_search_paths())
+}
+
+/// Returns the first executable file with the provided name found in the provided directories.
+/// See [which](fn.which.html) for more details.
+///
+/// # Arguments
+///
+/// * `name` - The executable name
+/// * `search_paths` - The directories to search in
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let missing: Option<PathBuf> = path::which_in("cargo", &["./src"]);
+///
+///     assert!(missing.is_none());
+/// }
+/// ```
+pub fn which_in<P: AsPath, R: FromPath>(name: &str, search_paths: &[P]) -> Option<R> {
+    which::find(name, search_paths, false)
+        .first()
+        .map(|path| FromPath::from_path(path))
+}
+
+/// Returns all the executable files with the provided name found in the provided directories,
+/// in the search order.
+/// See [which](fn.which.html) for more details.
+///
+/// # Arguments
+///
+/// * `name` - The executable name
+/// * `search_paths` - The directories to search in
+///
+/// # Example
+///
+/// ```
+/// use fsio::path;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let all: Vec<PathBuf> = path::which_all_in("cargo", &["./src", "./tests"]);
+///
+///     assert!(all.is_empty());
+/// }
+/// ```
+pub fn which_all_in<P: AsPath, R: FromPath>(name: &str, search_paths: &[P]) -> Vec<R> {
+    which::find(name, search_paths, true)
+        .iter()
+        .map(|path| FromPath::from_path(path))
+        .collect()
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[test]
 fn canonicalize_as_string_valid() {
@@ -36,6 +36,247 @@ fn canonicalize_or_invalid() {
     assert_eq!(result, "/src/path/mod.rs.2");
 }
 
+#[test]
+fn normalize_current_directory() {
+    let result: PathBuf = normalize("./a/./b/.");
+
+    assert_eq!(result, PathBuf::from("a/b"));
+}
+
+#[test]
+fn normalize_duplicate_separators() {
+    let result: PathBuf = normalize("a//b///c");
+
+    assert_eq!(result, PathBuf::from("a/b/c"));
+}
+
+#[test]
+fn normalize_parent_directory() {
+    let result: PathBuf = normalize("a/b/../c/../../d");
+
+    assert_eq!(result, PathBuf::from("d"));
+}
+
+#[test]
+fn normalize_leading_parent_directory() {
+    let result: PathBuf = normalize("../../a/../b");
+
+    assert_eq!(result, PathBuf::from("../../b"));
+}
+
+#[test]
+fn normalize_above_relative_start() {
+    let result: PathBuf = normalize("a/../../b");
+
+    assert_eq!(result, PathBuf::from("../b"));
+}
+
+#[test]
+fn normalize_above_root() {
+    let result: PathBuf = normalize("/a/../../b");
+
+    assert_eq!(result, PathBuf::from("/b"));
+}
+
+#[test]
+fn normalize_empty_result() {
+    let result: PathBuf = normalize("a/..");
+
+    assert_eq!(result, PathBuf::from("."));
+}
+
+#[test]
+fn normalize_path_buf() {
+    let result: PathBuf = normalize(&PathBuf::from("/a/./b"));
+
+    assert_eq!(result, PathBuf::from("/a/b"));
+}
+
+#[test]
+fn relative_to_same_path() {
+    let result: PathBuf = relative_to("a/b", "./a/b/").unwrap();
+
+    assert_eq!(result, PathBuf::from("."));
+}
+
+#[test]
+fn relative_to_child() {
+    let result: PathBuf = relative_to("/a/b/c/d.txt", "/a/b").unwrap();
+
+    assert_eq!(result, PathBuf::from("c/d.txt"));
+}
+
+#[test]
+fn relative_to_parent() {
+    let result: PathBuf = relative_to("/a", "/a/b/c").unwrap();
+
+    assert_eq!(result, PathBuf::from("../.."));
+}
+
+#[test]
+fn relative_to_sibling() {
+    let result: PathBuf = relative_to("a/x/../b/file.txt", "a/c").unwrap();
+
+    assert_eq!(result, PathBuf::from("../b/file.txt"));
+}
+
+#[test]
+fn relative_to_current_directory() {
+    let result: PathBuf = relative_to("../a", ".").unwrap();
+
+    assert_eq!(result, PathBuf::from("../a"));
+}
+
+#[test]
+fn relative_to_mixed_absolute() {
+    let result: Result<PathBuf, FsIOError> = relative_to("/a", "a");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn relative_to_base_above() {
+    let result: Result<PathBuf, FsIOError> = relative_to("a", "../b");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn canonical_relative_to_valid() {
+    let result: PathBuf = canonical_relative_to("./src/path/mod.rs", "./tests").unwrap();
+
+    assert_eq!(result, PathBuf::from("../src/path/mod.rs"));
+}
+
+#[test]
+fn canonical_relative_to_not_exists() {
+    let result: Result<PathBuf, FsIOError> = canonical_relative_to("./src/path/mod.rs", "./bad");
+
+    match result {
+        Err(FsIOError::IOError(message, _)) => assert!(message.contains("./bad")),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn expand_valid() {
+    std::env::set_var("FSIO_TEST_PATH_EXPAND", "value");
+
+    let expanded: PathBuf = expand("./${FSIO_TEST_PATH_EXPAND}/$FSIO_TEST_PATH_UNDEFINED/file");
+
+    assert_eq!(expanded, PathBuf::from("./value//file"));
+}
+
+#[test]
+fn expand_strict_valid() {
+    std::env::set_var("FSIO_TEST_PATH_EXPAND_STRICT", "value");
+
+    let expanded: String = expand_strict("./${FSIO_TEST_PATH_EXPAND_STRICT}/file").unwrap();
+
+    assert_eq!(expanded, "./value/file");
+}
+
+#[test]
+fn expand_strict_undefined() {
+    let result: Result<String, FsIOError> = expand_strict("./$FSIO_TEST_PATH_UNDEFINED/file");
+
+    match result {
+        Err(FsIOError::UndefinedVariable(_)) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+#[cfg(not(windows))]
+fn expand_not_unicode() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = Path::new(OsStr::from_bytes(b"./$FSIO_TEST_PATH_UNDEFINED/\xff"));
+
+    let expanded: PathBuf = expand(&path);
+    assert_eq!(expanded, path);
+
+    let result: Result<PathBuf, FsIOError> = expand_strict(&path);
+    match result {
+        Err(FsIOError::InvalidPath(_)) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn join_within_valid() {
+    let joined: PathBuf = join_within("base", "./a/../b/./c.txt").unwrap();
+
+    assert_eq!(joined, PathBuf::from("base/b/c.txt"));
+}
+
+#[test]
+fn join_within_empty() {
+    let joined: PathBuf = join_within("base", "").unwrap();
+
+    assert_eq!(joined, PathBuf::from("base"));
+}
+
+#[test]
+fn join_within_absolute() {
+    let result: Result<PathBuf, FsIOError> = join_within("base", "/etc/passwd");
+
+    match result {
+        Err(FsIOError::PathOutsideBase(_)) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn join_within_parent_directory() {
+    let result: Result<PathBuf, FsIOError> = join_within("base", "a/../../b");
+
+    match result {
+        Err(FsIOError::PathOutsideBase(_)) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn join_within_checked_not_exists() {
+    let joined: PathBuf = join_within_checked("./src", "path/bad/file.txt").unwrap();
+
+    assert_eq!(joined, PathBuf::from("./src/path/bad/file.txt"));
+}
+
+#[test]
+fn join_within_checked_base_not_exists() {
+    let result: Result<PathBuf, FsIOError> = join_within_checked("./badpath", "file.txt");
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(not(windows))]
+fn join_within_checked_symlink_outside() {
+    use std::os::unix::fs::symlink;
+
+    let base = "./target/__test/ut/path_test/join_within_checked_symlink_outside/base";
+    crate::directory::delete(base).unwrap();
+    crate::directory::create(base).unwrap();
+    symlink("../..", &format!("{}/link", base)).unwrap();
+    symlink("./missing", &format!("{}/broken", base)).unwrap();
+    symlink(".", &format!("{}/self", base)).unwrap();
+
+    let mut result: Result<PathBuf, FsIOError> = join_within_checked(base, "link/file.txt");
+    match result {
+        Err(FsIOError::PathOutsideBase(_)) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    result = join_within_checked(base, "broken/file.txt");
+    assert!(result.is_err());
+
+    result = join_within_checked(base, "self/file.txt");
+    assert!(result.is_ok());
+}
+
 #[test]
 fn get_basename_only_filename() {
     let result = get_basename("test.txt").unwrap();
@@ -138,6 +379,75 @@ fn get_last_modified_time_not_found() {
     assert!(result.is_err());
 }
 
+#[test]
+fn matches_glob_valid() {
+    assert!(matches_glob("./src/**/*.rs", &Path::new("src/path/mod.rs")));
+    assert!(matches_glob("src/{file,directory}.rs", "src/file.rs"));
+}
+
+#[test]
+fn matches_glob_invalid() {
+    assert!(!matches_glob("src/*.rs", "src/path/mod.rs"));
+}
+
+#[test]
+fn glob_files() {
+    let paths: Vec<PathBuf> = glob("src/path/*_test.{rs,txt}").unwrap();
+
+    assert!(paths.contains(&PathBuf::from("src/path/mod_test.rs")));
+    assert!(paths.contains(&PathBuf::from("src/path/glob_test.rs")));
+    assert!(!paths.contains(&PathBuf::from("src/path/mod.rs")));
+}
+
+#[test]
+fn glob_recursive() {
+    let paths: Vec<PathBuf> = glob("./src/**/mod.rs").unwrap();
+
+    assert_eq!(paths, vec![PathBuf::from("src/path/mod.rs")]);
+}
+
+#[test]
+fn glob_no_wildcards() {
+    let paths: Vec<String> = glob("Cargo.toml").unwrap();
+
+    assert_eq!(paths, vec!["Cargo.toml"]);
+}
+
+#[test]
+fn glob_not_exists() {
+    let paths: Vec<String> = glob("./badpath/**/*.rs").unwrap();
+
+    assert!(paths.is_empty());
+}
+
+#[test]
+fn which_found() {
+    let result: Option<PathBuf> = which("cargo");
+
+    assert!(result.unwrap().is_file());
+}
+
+#[test]
+fn which_not_found() {
+    let result: Option<PathBuf> = which("fsio_unknown_executable");
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn which_empty_name() {
+    let result: Option<PathBuf> = which("");
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn which_all_found() {
+    let result: Vec<PathBuf> = which_all("cargo");
+
+    assert!(!result.is_empty());
+}
+
 #[test]
 #[cfg(feature = "temp-path")]
 fn get_temporary_file_path_valid() {
@@ -147,3 +457,110 @@ fn get_temporary_file_path_valid() {
     assert!(temp_file.ends_with(".txt"));
     assert!(temp_file.contains(name));
 }
+
+#[test]
+#[cfg(feature = "temp-path")]
+fn create_temporary_file_valid() {
+    let (temp_file, _) = create_temporary_file("txt").unwrap();
+
+    let name = env!("CARGO_PKG_NAME");
+    assert!(temp_file.ends_with(".txt"));
+    assert!(temp_file.contains(name));
+    assert!(Path::new(&temp_file).is_file());
+
+    fs::remove_file(&temp_file).unwrap();
+}
+
+#[test]
+#[cfg(feature = "temp-path")]
+fn create_temporary_file_unique() {
+    let (temp_file1, _) = create_temporary_file("txt").unwrap();
+    let (temp_file2, _) = create_temporary_file("txt").unwrap();
+
+    assert_ne!(temp_file1, temp_file2);
+
+    fs::remove_file(&temp_file1).unwrap();
+    fs::remove_file(&temp_file2).unwrap();
+}
+
+#[test]
+#[cfg(all(feature = "temp-path", not(windows)))]
+fn create_temporary_file_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let (temp_file, _) = create_temporary_file("txt").unwrap();
+
+    let mode = fs::metadata(&temp_file).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    fs::remove_file(&temp_file).unwrap();
+}
+
+#[test]
+#[cfg(feature = "temp-path")]
+fn temp_file_deleted_on_drop() {
+    use std::io::Write;
+
+    let path: PathBuf;
+    {
+        let mut temp_file = TempFile::new("txt").unwrap();
+        temp_file.file().write_all("some content".as_bytes()).unwrap();
+
+        path = temp_file.path().to_path_buf();
+        assert!(path.is_file());
+        assert_eq!(crate::file::read_text_file(&temp_file).unwrap(), "some content");
+    }
+
+    assert!(!path.exists());
+}
+
+#[test]
+#[cfg(feature = "temp-path")]
+fn temp_file_persist() {
+    let temp_file = TempFile::new("txt").unwrap();
+    let path = temp_file.persist();
+
+    assert!(path.is_file());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[cfg(feature = "temp-path")]
+fn temp_dir_deleted_on_drop() {
+    let path: PathBuf;
+    {
+        let temp_dir = TempDir::new().unwrap();
+        crate::file::ensure_exists(&temp_dir.path().join("dir/file.txt")).unwrap();
+
+        path = temp_dir.path().to_path_buf();
+        assert!(path.is_dir());
+        assert!(path.to_string_lossy().contains(env!("CARGO_PKG_NAME")));
+    }
+
+    assert!(!path.exists());
+}
+
+#[test]
+#[cfg(feature = "temp-path")]
+fn temp_dir_keep() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.keep();
+
+    assert!(path.is_dir());
+
+    fs::remove_dir_all(&path).unwrap();
+}
+
+#[test]
+#[cfg(feature = "temp-path")]
+fn cleanup_temporary_files_keeps_new_files() {
+    let (temp_file, _) = create_temporary_file("txt").unwrap();
+
+    let report = cleanup_temporary_files(Duration::from_secs(24 * 60 * 60)).unwrap();
+
+    assert!(!report.removed.contains(&PathBuf::from(&temp_file)));
+    assert!(Path::new(&temp_file).exists());
+
+    fs::remove_file(&temp_file).unwrap();
+}
@@ -1,11 +1,32 @@
+#[cfg(test)]
+#[path = "./temp_path_test.rs"]
+mod temp_path_test;
+
+use crate::error::FsIOError;
+use crate::path::as_path::AsPath;
 use crate::path::from_path::FromPath;
 use rand::distributions::Alphanumeric;
-use rand::{thread_rng, Rng};
+use rand::rngs::StdRng;
+use rand::{thread_rng, Rng, SeedableRng};
 use std::env;
+use std::fs::{
+    read_dir, remove_dir_all, remove_file, symlink_metadata, DirBuilder, File, OpenOptions,
+};
+use std::io;
+use std::io::ErrorKind;
 use std::iter;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+#[cfg(not(windows))]
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
+#[cfg(not(windows))]
+use users::{get_current_username, get_effective_uid};
 
+const CREATE_ATTEMPTS: usize = 100;
 #[cfg(not(windows))]
-use users::get_current_username;
+const PRIVATE_DIRECTORY_NAME: &str = "private";
 
 #[cfg(windows)]
 fn get_additional_temp_path() -> Option<String> {
@@ -25,27 +46,676 @@ fn get_additional_temp_path() -> Option<String> {
     }
 }
 
-pub(crate) fn get(extension: &str) -> String {
+/// Returns the directories created under the OS temp directory, ending with the temp directory.
+fn get_private_directories() -> Vec<PathBuf> {
     let name = env!("CARGO_PKG_NAME");
 
-    let mut rng = thread_rng();
-    let file_name: String = iter::repeat(())
+    let mut directory = env::temp_dir();
+    let mut directories = vec![];
+
+    match get_additional_temp_path() {
+        Some(additional_path) => {
+            directory.push(additional_path);
+            directories.push(directory.clone());
+        }
+        None => {}
+    };
+
+    directory.push(name);
+    directories.push(directory);
+
+    directories
+}
+
+fn get_directory() -> PathBuf {
+    let mut directories = get_private_directories();
+
+    directories.pop().unwrap_or_else(env::temp_dir)
+}
+
+fn random_string<R: Rng>(rng: &mut R, length: usize) -> String {
+    iter::repeat(())
         .map(|()| rng.sample(Alphanumeric))
         .map(char::from)
-        .take(10)
-        .collect();
+        .take(length)
+        .collect()
+}
 
-    let mut file_path = env::temp_dir();
+/// Returns the start and end character indexes of the last sequence of at least 3 'X'
+/// characters in the template.
+fn find_template_random_range(chars: &[char]) -> Option<(usize, usize)> {
+    let mut end = chars.len();
+    while end > 0 {
+        if chars[end - 1] == 'X' {
+            let mut start = end - 1;
+            while start > 0 && chars[start - 1] == 'X' {
+                start = start - 1;
+            }
 
-    match get_additional_temp_path() {
-        Some(additional_path) => file_path.push(additional_path),
-        None => {}
+            if end - start >= 3 {
+                return Some((start, end));
+            }
+
+            end = start;
+        } else {
+            end = end - 1;
+        }
+    }
+
+    None
+}
+
+/// Replaces the last sequence of at least 3 'X' characters with random characters.
+fn apply_template<F: FnMut(usize) -> String>(template: &str, mut random: F) -> String {
+    let chars: Vec<char> = template.chars().collect();
+
+    match find_template_random_range(&chars) {
+        Some((start, end)) => {
+            let prefix: String = chars[..start].iter().collect();
+            let suffix: String = chars[end..].iter().collect();
+            format!("{}{}{}", prefix, random(end - start), suffix)
+        }
+        None => template.to_string(),
+    }
+}
+
+#[cfg(windows)]
+fn set_private_directory(_dir_builder: &mut DirBuilder) {}
+
+#[cfg(not(windows))]
+fn set_private_directory(dir_builder: &mut DirBuilder) {
+    dir_builder.mode(0o700);
+}
+
+#[cfg(windows)]
+fn set_private_file(_options: &mut OpenOptions) {}
+
+#[cfg(not(windows))]
+fn set_private_file(options: &mut OpenOptions) {
+    options.mode(0o600);
+}
+
+#[cfg(windows)]
+fn prepare_private_directory(directory: &Path, _fallback: bool) -> Result<PathBuf, FsIOError> {
+    Ok(directory.to_path_buf())
+}
+
+/// Ensures the directory is private to the current user, as the shared temp directory allows
+/// anyone to create it before we do, and returns the directory to use.
+/// Directories owned by the current user are made private (older versions created them with
+/// the default permissions) and if that is not possible, a private sub directory is used
+/// instead in case fallback is allowed.
+#[cfg(not(windows))]
+fn prepare_private_directory(directory: &Path, fallback: bool) -> Result<PathBuf, FsIOError> {
+    use std::fs::{set_permissions, Permissions};
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    let metadata = match symlink_metadata(directory) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read metadata for: {:?}", &directory).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    };
+
+    if !metadata.file_type().is_dir() {
+        return Err(FsIOError::IOError(
+                format!("Temporary directory: {:?} is not a directory.", &directory).to_string(),
+                None,
+            ),
+        );
+    }
+    if metadata.uid() != get_effective_uid() {
+        return Err(FsIOError::IOError(
+                format!(
+                    "Temporary directory: {:?} is not owned by the current user.",
+                    &directory
+                )
+                .to_string(),
+                None,
+            ),
+        );
+    }
+
+    if metadata.mode() & 0o077 == 0
+        || set_permissions(directory, Permissions::from_mode(0o700)).is_ok()
+    {
+        return Ok(directory.to_path_buf());
+    }
+
+    if !fallback {
+        return Err(FsIOError::IOError(
+                format!(
+                    "Temporary directory: {:?} is accessible by other users.",
+                    &directory
+                )
+                .to_string(),
+                None,
+            ),
+        );
+    }
+
+    let private_directory = directory.join(PRIVATE_DIRECTORY_NAME);
+    match create_directory(&private_directory) {
+        Ok(_) => (),
+        Err(ref error) if error.kind() == ErrorKind::AlreadyExists => (),
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to create directory: {:?}.", &private_directory).to_string(),
+                    Some(error),
+                ),
+            )
+        }
+    }
+
+    prepare_private_directory(&private_directory, false)
+}
+
+fn create_file(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.read(true).write(true).create_new(true);
+    set_private_file(&mut options);
+
+    options.open(path)
+}
+
+fn create_directory(path: &Path) -> io::Result<()> {
+    let mut dir_builder = DirBuilder::new();
+    set_private_directory(&mut dir_builder);
+
+    dir_builder.create(path)
+}
+
+/// Builds temporary paths based on custom naming rules.
+///
+/// By default, names are made of 10 random alphanumeric characters and are located under the
+/// `<OS temp directory>/<user name>/fsio` directory.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path::TempPathBuilder;
+///
+/// fn main() {
+///     let temp_file = TempPathBuilder::new()
+///         .template("build-XXXXXX.log")
+///         .temp_file()
+///         .unwrap();
+///
+///     let name = temp_file.path().file_name().unwrap().to_string_lossy();
+///     assert!(name.starts_with("build-"));
+///     assert!(name.ends_with(".log"));
+///     assert_eq!(name.len(), 16);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TempPathBuilder {
+    prefix: String,
+    suffix: String,
+    random_length: usize,
+    template: Option<String>,
+    root: Option<PathBuf>,
+    rng: Option<StdRng>,
+}
+
+impl TempPathBuilder {
+    /// Returns new instance with default values.
+    pub fn new() -> TempPathBuilder {
+        TempPathBuilder {
+            prefix: "".to_string(),
+            suffix: "".to_string(),
+            random_length: 10,
+            template: None,
+            root: None,
+            rng: None,
+        }
+    }
+
+    /// Sets the text added before the random part of the name.
+    pub fn prefix(&mut self, prefix: &str) -> &mut TempPathBuilder {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Sets the text added after the random part of the name (for example `.txt`).
+    pub fn suffix(&mut self, suffix: &str) -> &mut TempPathBuilder {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Sets the number of random characters in the name (default 10).
+    pub fn random_length(&mut self, random_length: usize) -> &mut TempPathBuilder {
+        self.random_length = random_length;
+        self
+    }
+
+    /// Sets a name template, in which the last sequence of at least 3 `X` characters is replaced
+    /// with random characters, for example `build-XXXXXX.log`.
+    /// When set, the prefix, suffix and random length values are ignored.
+    /// Creating paths from a template without such a sequence fails with an InvalidPath error,
+    /// as every attempt would result in the same name.
+    pub fn template(&mut self, template: &str) -> &mut TempPathBuilder {
+        self.template = Some(template.to_string());
+        self
+    }
+
+    /// Sets the directory in which the temporary paths are located.
+    pub fn root<T: AsPath + ?Sized>(&mut self, root: &T) -> &mut TempPathBuilder {
+        self.root = Some(root.as_path().to_path_buf());
+        self
+    }
+
+    /// Sets a seed for the random name generation, so the same sequence of names is generated
+    /// on every run (for example for reproducible tests).
+    pub fn seed(&mut self, seed: u64) -> &mut TempPathBuilder {
+        self.rng = Some(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    fn random(&mut self, length: usize) -> String {
+        match self.rng {
+            Some(ref mut rng) => random_string(rng, length),
+            None => random_string(&mut thread_rng(), length),
+        }
+    }
+
+    fn get_root(&self) -> PathBuf {
+        match self.root {
+            Some(ref root) => root.clone(),
+            None => get_directory(),
+        }
+    }
+
+    fn generate_path(&mut self, directory: &Path) -> PathBuf {
+        let name = match self.template.clone() {
+            Some(template) => apply_template(&template, |length| self.random(length)),
+            None => {
+                let random_length = self.random_length;
+                let random = self.random(random_length);
+                format!("{}{}{}", self.prefix, random, self.suffix)
+            }
+        };
+
+        directory.join(name)
+    }
+
+    fn create_unique<T>(
+        &mut self,
+        create_path: &Fn(&Path) -> io::Result<T>,
+    ) -> Result<(PathBuf, T), FsIOError> {
+        if let Some(ref template) = self.template {
+            let chars: Vec<char> = template.chars().collect();
+            if find_template_random_range(&chars).is_none() {
+                return Err(FsIOError::InvalidPath(
+                        format!(
+                            "Template: {} does not contain a sequence of at least 3 X characters.",
+                            template
+                        )
+                        .to_string(),
+                    ),
+                );
+            }
+        }
+
+        let mut directory = self.get_root();
+        let mut dir_builder = DirBuilder::new();
+        dir_builder.recursive(true);
+        set_private_directory(&mut dir_builder);
+
+        if let Err(error) = dir_builder.create(&directory) {
+            return Err(FsIOError::IOError(
+                    format!("Unable to create directory: {:?}.", &directory).to_string(),
+                    Some(error),
+                ),
+            );
+        }
+
+        // the mode only applies to newly created directories, so the shared default
+        // directories are validated in case they already existed
+        if self.root.is_none() {
+            let mut private_directories = get_private_directories();
+            let last_directory = private_directories.pop();
+            for private_directory in private_directories {
+                prepare_private_directory(&private_directory, false)?;
+            }
+            if let Some(last_directory) = last_directory {
+                directory = prepare_private_directory(&last_directory, true)?;
+            }
+        }
+
+        let mut attempt = 1;
+        loop {
+            let path = self.generate_path(&directory);
+
+            match create_path(&path) {
+                Ok(value) => return Ok((path, value)),
+                Err(ref error)
+                    if error.kind() == ErrorKind::AlreadyExists && attempt < CREATE_ATTEMPTS =>
+                {
+                    attempt = attempt + 1
+                }
+                Err(error) => {
+                    return Err(FsIOError::IOError(
+                            format!("Unable to create temporary path: {:?}", &path).to_string(),
+                            Some(error),
+                        ),
+                    )
+                }
+            }
+        }
+    }
+
+    /// Returns a new temporary path, without creating it.
+    pub fn path<R: FromPath>(&mut self) -> R {
+        let directory = self.get_root();
+
+        FromPath::from_path(&self.generate_path(&directory))
+    }
+
+    /// Creates a new temporary file and returns its path and the open (read/write) file handle.
+    /// See [create_temporary_file](fn.create_temporary_file.html) for more details.
+    pub fn create_file<R: FromPath>(&mut self) -> Result<(R, File), FsIOError> {
+        let (path, fd) = self.create_unique(&create_file)?;
+
+        Ok((FromPath::from_path(&path), fd))
+    }
+
+    /// Creates a new temporary file which is deleted when dropped.
+    pub fn temp_file(&mut self) -> Result<TempFile, FsIOError> {
+        let (path, file) = self.create_unique(&create_file)?;
+
+        Ok(TempFile { path, file })
+    }
+
+    /// Creates a new temporary directory which is deleted when dropped.
+    pub fn temp_dir(&mut self) -> Result<TempDir, FsIOError> {
+        let (path, _) = self.create_unique(&create_directory)?;
+
+        Ok(TempDir { path })
+    }
+}
+
+impl Default for TempPathBuilder {
+    fn default() -> Self {
+        TempPathBuilder::new()
+    }
+}
+
+fn get_extension_suffix(extension: &str) -> String {
+    if extension.is_empty() {
+        "".to_string()
+    } else {
+        format!(".{}", extension)
+    }
+}
+
+pub(crate) fn get(extension: &str) -> String {
+    TempPathBuilder::new()
+        .suffix(&get_extension_suffix(extension))
+        .path()
+}
+
+pub(crate) fn create(extension: &str) -> Result<(String, File), FsIOError> {
+    TempPathBuilder::new()
+        .suffix(&get_extension_suffix(extension))
+        .create_file()
+}
+
+/// A temporary file which is deleted when dropped.
+#[derive(Debug)]
+pub struct TempFile {
+    path: PathBuf,
+    file: File,
+}
+
+impl TempFile {
+    /// Creates a new temporary file, see
+    /// [create_temporary_file](fn.create_temporary_file.html) for more details.
+    ///
+    /// # Arguments
+    ///
+    /// * `extension` - The file extension
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fsio::file;
+    /// use fsio::path::TempFile;
+    /// use std::path::PathBuf;
+    ///
+    /// fn main() {
+    ///     let path: PathBuf;
+    ///     {
+    ///         let temp_file = TempFile::new("txt").unwrap();
+    ///         file::write_text_file(&temp_file, "some content").unwrap();
+    ///
+    ///         path = temp_file.path().to_path_buf();
+    ///         assert!(path.exists());
+    ///     }
+    ///
+    ///     assert!(!path.exists());
+    /// }
+    /// ```
+    pub fn new(extension: &str) -> Result<TempFile, FsIOError> {
+        TempPathBuilder::new()
+            .suffix(&get_extension_suffix(extension))
+            .temp_file()
+    }
+
+    /// Returns the temporary file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the open (read/write) file handle.
+    pub fn file(&mut self) -> &mut File {
+        &mut self.file
+    }
+
+    /// Keeps the file on the file system and returns its path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fsio::file;
+    /// use fsio::path::TempFile;
+    ///
+    /// fn main() {
+    ///     let temp_file = TempFile::new("txt").unwrap();
+    ///     let path = temp_file.persist();
+    ///
+    ///     assert!(path.exists());
+    ///     file::delete(&path).unwrap();
+    /// }
+    /// ```
+    pub fn persist(mut self) -> PathBuf {
+        mem::replace(&mut self.path, PathBuf::new())
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        // the handle is still open, which is supported on windows as well since files
+        // are opened with delete sharing, and is closed right after
+        if !self.path.as_os_str().is_empty() {
+            remove_file(&self.path).unwrap_or(());
+        }
+    }
+}
+
+impl AsPath for TempFile {
+    fn as_path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// A temporary directory which is deleted (including its content) when dropped.
+#[derive(Debug)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates a new empty temporary directory.
+    /// On unix, the directory is created with 0700 permissions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fsio::file;
+    /// use fsio::path::TempDir;
+    /// use std::path::PathBuf;
+    ///
+    /// fn main() {
+    ///     let path: PathBuf;
+    ///     {
+    ///         let temp_dir = TempDir::new().unwrap();
+    ///         file::write_text_file(&temp_dir.path().join("file.txt"), "some content").unwrap();
+    ///
+    ///         path = temp_dir.path().to_path_buf();
+    ///         assert!(path.is_dir());
+    ///     }
+    ///
+    ///     assert!(!path.exists());
+    /// }
+    /// ```
+    pub fn new() -> Result<TempDir, FsIOError> {
+        TempPathBuilder::new().temp_dir()
+    }
+
+    /// Returns the temporary directory path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Keeps the directory on the file system and returns its path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fsio::directory;
+    /// use fsio::path::TempDir;
+    ///
+    /// fn main() {
+    ///     let temp_dir = TempDir::new().unwrap();
+    ///     let path = temp_dir.keep();
+    ///
+    ///     assert!(path.is_dir());
+    ///     directory::delete(&path).unwrap();
+    /// }
+    /// ```
+    pub fn keep(mut self) -> PathBuf {
+        mem::replace(&mut self.path, PathBuf::new())
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        if !self.path.as_os_str().is_empty() {
+            remove_dir_all(&self.path).unwrap_or(());
+        }
+    }
+}
+
+impl AsPath for TempDir {
+    fn as_path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Holds the result of a temporary files cleanup.
+#[derive(Debug)]
+pub struct CleanupReport {
+    /// The removed paths
+    pub removed: Vec<PathBuf>,
+    /// The paths which could not be removed, with the relevant error
+    pub failed: Vec<(PathBuf, FsIOError)>,
+}
+
+fn remove_path(path: &Path, is_directory: bool) -> Result<(), FsIOError> {
+    let result = if is_directory {
+        remove_dir_all(path)
+    } else {
+        remove_file(path)
+    };
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(FsIOError::IOError(
+                format!("Unable to delete: {:?}", &path).to_string(),
+                Some(error),
+            ),
+        ),
+    }
+}
+
+fn cleanup_directory(directory: &Path, max_age: Duration) -> Result<CleanupReport, FsIOError> {
+    let mut report = CleanupReport {
+        removed: vec![],
+        failed: vec![],
+    };
+
+    if !directory.exists() {
+        return Ok(report);
+    }
+
+    let entries = match read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) => {
+            return Err(FsIOError::IOError(
+                    format!("Unable to read directory: {:?}", &directory).to_string(),
+                    Some(error),
+                ),
+            )
+        }
     };
 
-    file_path.push(name);
+    let now = SystemTime::now();
+    for entry in entries {
+        let path = match entry {
+            Ok(entry) => entry.path(),
+            Err(error) => {
+                return Err(FsIOError::IOError(
+                        format!("Unable to read directory: {:?}", &directory).to_string(),
+                        Some(error),
+                    ),
+                )
+            }
+        };
+
+        let path_metadata = match symlink_metadata(&path) {
+            Ok(value) => value,
+            Err(error) => {
+                report.failed.push((
+                    path.clone(),
+                    FsIOError::IOError(
+                        format!("Unable to read metadata for: {:?}", &path).to_string(),
+                        Some(error),
+                    ),
+                ));
+                continue;
+            }
+        };
 
-    file_path.push(file_name);
-    file_path.set_extension(extension);
+        let age = path_metadata
+            .modified()
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok());
+        match age {
+            Some(age) if age >= max_age => match remove_path(&path, path_metadata.is_dir()) {
+                Ok(_) => report.removed.push(path),
+                Err(error) => report.failed.push((path, error)),
+            },
+            _ => (),
+        }
+    }
+
+    Ok(report)
+}
 
-    FromPath::from_path(&file_path)
+pub(crate) fn cleanup(max_age: Duration) -> Result<CleanupReport, FsIOError> {
+    cleanup_directory(&get_directory(), max_age)
 }
@@ -0,0 +1,243 @@
+use super::*;
+
+#[test]
+fn apply_template_no_placeholder() {
+    let name = apply_template("file.txt", |length| "r".repeat(length));
+
+    assert_eq!(name, "file.txt");
+}
+
+#[test]
+fn apply_template_last_placeholder() {
+    let name = apply_template("XXX-build-XXXXXX.log", |length| "r".repeat(length));
+
+    assert_eq!(name, "XXX-build-rrrrrr.log");
+}
+
+#[test]
+fn apply_template_short_sequence() {
+    let name = apply_template("aXXXbXX.tXt", |length| "r".repeat(length));
+
+    assert_eq!(name, "arrrbXX.tXt");
+}
+
+#[test]
+fn builder_path_default() {
+    let path: PathBuf = TempPathBuilder::new().path();
+
+    assert!(path.starts_with(get_directory()));
+    assert_eq!(path.file_name().unwrap().len(), 10);
+}
+
+#[test]
+fn builder_path_prefix_suffix_length() {
+    let path: PathBuf = TempPathBuilder::new()
+        .prefix("pre-")
+        .suffix(".txt")
+        .random_length(4)
+        .path();
+
+    let name = path.file_name().unwrap().to_string_lossy().into_owned();
+    assert!(name.starts_with("pre-"));
+    assert!(name.ends_with(".txt"));
+    assert_eq!(name.len(), 12);
+}
+
+#[test]
+fn builder_path_root() {
+    let path: PathBuf = TempPathBuilder::new()
+        .root("./target/__test/ut/temp_path_test")
+        .path();
+
+    assert_eq!(
+        path.parent().unwrap(),
+        Path::new("./target/__test/ut/temp_path_test")
+    );
+}
+
+#[test]
+fn builder_seed_reproducible() {
+    let mut builder1 = TempPathBuilder::new();
+    builder1.seed(42);
+    let mut builder2 = TempPathBuilder::new();
+    builder2.seed(42);
+
+    let first1: String = builder1.path();
+    let second1: String = builder1.path();
+    let first2: String = builder2.path();
+    let second2: String = builder2.path();
+
+    assert_eq!(first1, first2);
+    assert_eq!(second1, second2);
+    assert_ne!(first1, second1);
+}
+
+#[test]
+fn builder_create_file_root() {
+    let (path, _): (PathBuf, File) = TempPathBuilder::new()
+        .root("./target/__test/ut/temp_path_test/builder_create_file_root")
+        .suffix(".txt")
+        .create_file()
+        .unwrap();
+
+    assert!(path.is_file());
+}
+
+#[test]
+fn builder_create_file_collision() {
+    let root = "./target/__test/ut/temp_path_test/builder_create_file_collision";
+    let (first, _): (PathBuf, File) = TempPathBuilder::new()
+        .root(root)
+        .seed(1)
+        .create_file()
+        .unwrap();
+    let (second, _): (PathBuf, File) = TempPathBuilder::new()
+        .root(root)
+        .seed(1)
+        .create_file()
+        .unwrap();
+
+    assert_ne!(first, second);
+    assert!(first.is_file());
+    assert!(second.is_file());
+}
+
+#[test]
+fn builder_temp_dir_template() {
+    let temp_dir = TempPathBuilder::new()
+        .root("./target/__test/ut/temp_path_test/builder_temp_dir_template")
+        .template("dir-XXXX")
+        .temp_dir()
+        .unwrap();
+
+    let name = temp_dir.path().file_name().unwrap().to_string_lossy().into_owned();
+    assert!(temp_dir.path().is_dir());
+    assert!(name.starts_with("dir-"));
+    assert_eq!(name.len(), 8);
+}
+
+#[test]
+fn builder_invalid_template() {
+    let root = "./target/__test/ut/temp_path_test/builder_invalid_template";
+
+    let result = TempPathBuilder::new().root(root).template("file-XX.txt").temp_file();
+
+    match result {
+        Err(FsIOError::InvalidPath(_)) => (),
+        _ => panic!("Invalid result: {:?}", result),
+    }
+    assert!(!Path::new(root).exists());
+}
+
+fn set_modified(path: &Path, age: Duration) {
+    let modified = SystemTime::now() - age;
+    File::open(path).unwrap().set_modified(modified).unwrap();
+}
+
+#[test]
+fn cleanup_directory_not_exists() {
+    let report = cleanup_directory(
+        Path::new("./target/__test/ut/temp_path_test/cleanup_directory_not_exists"),
+        Duration::from_secs(0),
+    )
+    .unwrap();
+
+    assert!(report.removed.is_empty());
+    assert!(report.failed.is_empty());
+}
+
+#[test]
+fn cleanup_directory_by_age() {
+    let root = "./target/__test/ut/temp_path_test/cleanup_directory_by_age";
+    let mut builder = TempPathBuilder::new();
+    builder.root(root);
+
+    let old_file = builder.temp_file().unwrap().persist();
+    let new_file = builder.temp_file().unwrap().persist();
+    let old_directory = builder.temp_dir().unwrap().keep();
+    crate::file::ensure_exists(&old_directory.join("file.txt")).unwrap();
+
+    let day = Duration::from_secs(24 * 60 * 60);
+    set_modified(&old_file, day * 2);
+    set_modified(&old_directory, day * 2);
+
+    let report = cleanup_directory(Path::new(root), day).unwrap();
+
+    assert_eq!(report.removed.len(), 2);
+    assert!(report.removed.contains(&old_file));
+    assert!(report.removed.contains(&old_directory));
+    assert!(report.failed.is_empty());
+    assert!(!old_file.exists());
+    assert!(!old_directory.exists());
+    assert!(new_file.exists());
+}
+
+#[test]
+fn default_directories_private() {
+    TempPathBuilder::new().temp_file().unwrap();
+
+    for directory in get_private_directories() {
+        assert_eq!(prepare_private_directory(&directory, false).unwrap(), directory);
+    }
+}
+
+#[test]
+#[cfg(not(windows))]
+fn default_directory_permissions_tightened() {
+    use std::fs::{metadata, set_permissions, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    // older versions created the directory with the default permissions
+    let directory = get_directory();
+    crate::directory::create(&directory).unwrap();
+    set_permissions(&directory, Permissions::from_mode(0o755)).unwrap();
+
+    let (path, _) = create("txt").unwrap();
+    remove_file(&path).unwrap();
+
+    assert!(Path::new(&path).starts_with(&directory));
+    assert_eq!(metadata(&directory).unwrap().permissions().mode() & 0o777, 0o700);
+}
+
+#[test]
+#[cfg(not(windows))]
+fn prepare_private_directory_permissions() {
+    use std::fs::{metadata, set_permissions, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    let directory = Path::new("./target/__test/ut/temp_path_test/prepare_private_directory");
+    crate::directory::create(&directory).unwrap();
+
+    set_permissions(directory, Permissions::from_mode(0o700)).unwrap();
+    assert_eq!(prepare_private_directory(directory, false).unwrap(), directory);
+
+    set_permissions(directory, Permissions::from_mode(0o755)).unwrap();
+    assert_eq!(prepare_private_directory(directory, false).unwrap(), directory);
+    assert_eq!(metadata(directory).unwrap().permissions().mode() & 0o777, 0o700);
+}
+
+#[test]
+#[cfg(not(windows))]
+fn prepare_private_directory_symlink() {
+    use std::os::unix::fs::symlink;
+
+    let root = "./target/__test/ut/temp_path_test/prepare_private_directory_symlink";
+    crate::directory::delete(root).unwrap();
+    crate::directory::create(root).unwrap();
+    let target = Path::new(root).join("target");
+    let link = Path::new(root).join("link");
+    create_directory(&target).unwrap();
+    symlink("target", &link).unwrap();
+
+    assert!(prepare_private_directory(&target, true).is_ok());
+    assert!(prepare_private_directory(&link, true).is_err());
+}
+
+#[test]
+#[cfg(not(windows))]
+fn prepare_private_directory_file() {
+    let file_path = "./target/__test/ut/temp_path_test/prepare_private_directory_file/file.txt";
+    crate::file::ensure_exists(file_path).unwrap();
+
+    assert!(prepare_private_directory(Path::new(file_path), true).is_err());
+}
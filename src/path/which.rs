@@ -0,0 +1,94 @@
+//! # which
+//!
+//! Executable lookup in the PATH search paths.
+//!
+
+#[cfg(test)]
+#[path = "./which_test.rs"]
+mod which_test;
+
+use crate::path::as_path::AsPath;
+use std::env;
+use std::fs::metadata;
+use std::path::{Path, PathBuf};
+
+#[cfg(windows)]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+#[cfg(not(windows))]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    match metadata(path) {
+        Ok(path_metadata) => {
+            path_metadata.is_file() && path_metadata.permissions().mode() & 0o111 != 0
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(windows)]
+fn get_candidates(path: &Path) -> Vec<PathBuf> {
+    let mut candidates = vec![path.to_path_buf()];
+
+    if path.extension().is_none() {
+        let extensions = env::var("PATHEXT").unwrap_or(".COM;.EXE;.BAT;.CMD".to_string());
+        for extension in extensions.split(';').filter(|value| !value.is_empty()) {
+            let mut candidate = path.as_os_str().to_os_string();
+            candidate.push(extension);
+            candidates.push(PathBuf::from(candidate));
+        }
+    }
+
+    candidates
+}
+
+#[cfg(not(windows))]
+fn get_candidates(path: &Path) -> Vec<PathBuf> {
+    vec![path.to_path_buf()]
+}
+
+pub(crate) fn get_search_paths() -> Vec<PathBuf> {
+    match env::var_os("PATH") {
+        Some(value) => env::split_paths(&value).collect(),
+        None => vec![],
+    }
+}
+
+pub(crate) fn find<P: AsPath>(name: &str, search_paths: &[P], all: bool) -> Vec<PathBuf> {
+    let name_path = Path::new(name);
+    if name.is_empty() {
+        return vec![];
+    }
+
+    // names with a directory component are not searched
+    if name_path.components().count() > 1 {
+        return get_candidates(name_path)
+            .into_iter()
+            .filter(|candidate| is_executable(candidate))
+            .take(1)
+            .collect();
+    }
+
+    let mut found = vec![];
+    for search_path in search_paths {
+        let directory = search_path.as_path();
+        if directory.as_os_str().is_empty() {
+            continue;
+        }
+
+        for candidate in get_candidates(&directory.join(name_path)) {
+            if is_executable(&candidate) && !found.contains(&candidate) {
+                found.push(candidate);
+
+                if !all {
+                    return found;
+                }
+            }
+        }
+    }
+
+    found
+}
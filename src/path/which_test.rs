@@ -0,0 +1,72 @@
+use super::*;
+
+#[cfg(not(windows))]
+fn create_executable(path: &str, mode: u32) {
+    use std::fs::{set_permissions, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    crate::file::ensure_exists(path).unwrap();
+    set_permissions(path, Permissions::from_mode(mode)).unwrap();
+}
+
+#[test]
+fn get_search_paths_from_env() {
+    let search_paths = get_search_paths();
+
+    assert!(!search_paths.is_empty());
+}
+
+#[test]
+fn find_empty_name() {
+    let found = find("", &get_search_paths(), true);
+
+    assert!(found.is_empty());
+}
+
+#[test]
+fn find_skips_empty_search_path() {
+    let found = find("cargo", &[""], true);
+
+    assert!(found.is_empty());
+}
+
+#[test]
+#[cfg(not(windows))]
+fn find_permissions() {
+    let root = "./target/__test/ut/which_test/find_permissions";
+    create_executable(&format!("{}/dir1/tool", root), 0o644);
+    create_executable(&format!("{}/dir2/tool", root), 0o755);
+    create_executable(&format!("{}/dir3/tool", root), 0o700);
+    crate::directory::create(&format!("{}/dir4/tool", root)).unwrap();
+
+    let search_paths = vec![
+        format!("{}/dir1", root),
+        format!("{}/dir4", root),
+        format!("{}/dir2", root),
+        format!("{}/dir3", root),
+    ];
+
+    let first = find("tool", &search_paths, false);
+    assert_eq!(first, vec![PathBuf::from(format!("{}/dir2/tool", root))]);
+
+    let all = find("tool", &search_paths, true);
+    assert_eq!(
+        all,
+        vec![
+            PathBuf::from(format!("{}/dir2/tool", root)),
+            PathBuf::from(format!("{}/dir3/tool", root))
+        ]
+    );
+}
+
+#[test]
+#[cfg(not(windows))]
+fn find_with_directory() {
+    let root = "./target/__test/ut/which_test/find_with_directory";
+    create_executable(&format!("{}/tool", root), 0o755);
+
+    let empty: Vec<String> = vec![];
+    let found = find(&format!("{}/tool", root), &empty, true);
+
+    assert_eq!(found, vec![PathBuf::from(format!("{}/tool", root))]);
+}
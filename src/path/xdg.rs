@@ -0,0 +1,231 @@
+//! # xdg
+//!
+//! XDG base directory resolution functions, based on the
+//! [XDG Base Directory Specification](https://specifications.freedesktop.org/basedir-spec/latest/).
+//! Environment variables holding relative paths are ignored as defined by the specification.
+//!
+
+#[cfg(test)]
+#[path = "./xdg_test.rs"]
+mod xdg_test;
+
+use crate::path::expand::get_home_directory;
+use crate::path::from_path::FromPath;
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+fn resolve_home(value: Option<OsString>, default: &str) -> Option<PathBuf> {
+    match value {
+        Some(ref value) if Path::new(value).is_absolute() => Some(PathBuf::from(value)),
+        _ => get_home_directory().map(|home| home.join(default)),
+    }
+}
+
+fn resolve_directories(value: Option<OsString>, defaults: &[&str]) -> Vec<PathBuf> {
+    let directories: Vec<PathBuf> = match value {
+        Some(ref value) => env::split_paths(value)
+            .filter(|directory| directory.is_absolute())
+            .collect(),
+        None => vec![],
+    };
+
+    if directories.is_empty() {
+        defaults.iter().map(PathBuf::from).collect()
+    } else {
+        directories
+    }
+}
+
+fn find_file(directories: &[PathBuf], app: &str, name: &str) -> Option<PathBuf> {
+    directories
+        .iter()
+        .map(|directory| directory.join(app).join(name))
+        .find(|file| file.is_file())
+}
+
+/// Returns the base directory for user specific configuration files.
+/// Defined by the `XDG_CONFIG_HOME` environment variable, defaults to `$HOME/.config`.
+/// Returns none if the home directory is unknown.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path::xdg;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let directory: Option<PathBuf> = xdg::config_home();
+///
+///     assert!(directory.unwrap().is_absolute());
+/// }
+/// ```
+pub fn config_home<R: FromPath>() -> Option<R> {
+    resolve_home(env::var_os("XDG_CONFIG_HOME"), ".config").map(|path| FromPath::from_path(&path))
+}
+
+/// Returns the base directory for user specific data files.
+/// Defined by the `XDG_DATA_HOME` environment variable, defaults to `$HOME/.local/share`.
+/// Returns none if the home directory is unknown.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path::xdg;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let directory: Option<PathBuf> = xdg::data_home();
+///
+///     assert!(directory.unwrap().is_absolute());
+/// }
+/// ```
+pub fn data_home<R: FromPath>() -> Option<R> {
+    resolve_home(env::var_os("XDG_DATA_HOME"), ".local/share")
+        .map(|path| FromPath::from_path(&path))
+}
+
+/// Returns the base directory for user specific non-essential (cached) data.
+/// Defined by the `XDG_CACHE_HOME` environment variable, defaults to `$HOME/.cache`.
+/// Returns none if the home directory is unknown.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path::xdg;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let directory: Option<PathBuf> = xdg::cache_home();
+///
+///     assert!(directory.unwrap().is_absolute());
+/// }
+/// ```
+pub fn cache_home<R: FromPath>() -> Option<R> {
+    resolve_home(env::var_os("XDG_CACHE_HOME"), ".cache").map(|path| FromPath::from_path(&path))
+}
+
+/// Returns the base directory for user specific state data (for example logs and history).
+/// Defined by the `XDG_STATE_HOME` environment variable, defaults to `$HOME/.local/state`.
+/// Returns none if the home directory is unknown.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path::xdg;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let directory: Option<PathBuf> = xdg::state_home();
+///
+///     assert!(directory.unwrap().is_absolute());
+/// }
+/// ```
+pub fn state_home<R: FromPath>() -> Option<R> {
+    resolve_home(env::var_os("XDG_STATE_HOME"), ".local/state")
+        .map(|path| FromPath::from_path(&path))
+}
+
+/// Returns the base directory for user specific runtime files (for example sockets).
+/// Defined by the `XDG_RUNTIME_DIR` environment variable, which has no default value.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path::xdg;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let directory: Option<PathBuf> = xdg::runtime_dir();
+///
+///     if let Some(directory) = directory {
+///         assert!(directory.is_absolute());
+///     }
+/// }
+/// ```
+pub fn runtime_dir<R: FromPath>() -> Option<R> {
+    match env::var_os("XDG_RUNTIME_DIR") {
+        Some(ref value) if Path::new(value).is_absolute() => {
+            Some(FromPath::from_path(Path::new(value)))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the preference ordered base directories to search for configuration files, in
+/// addition to the [config_home](fn.config_home.html) directory.
+/// Defined by the `XDG_CONFIG_DIRS` environment variable, defaults to `/etc/xdg`.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path::xdg;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let directories: Vec<PathBuf> = xdg::config_dirs();
+///
+///     assert!(!directories.is_empty());
+/// }
+/// ```
+pub fn config_dirs<R: FromPath>() -> Vec<R> {
+    resolve_directories(env::var_os("XDG_CONFIG_DIRS"), &["/etc/xdg"])
+        .iter()
+        .map(|path| FromPath::from_path(path))
+        .collect()
+}
+
+/// Returns the preference ordered base directories to search for data files, in addition to
+/// the [data_home](fn.data_home.html) directory.
+/// Defined by the `XDG_DATA_DIRS` environment variable, defaults to
+/// `/usr/local/share` and `/usr/share`.
+///
+/// # Example
+///
+/// ```
+/// use fsio::path::xdg;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let directories: Vec<PathBuf> = xdg::data_dirs();
+///
+///     assert!(!directories.is_empty());
+/// }
+/// ```
+pub fn data_dirs<R: FromPath>() -> Vec<R> {
+    resolve_directories(
+        env::var_os("XDG_DATA_DIRS"),
+        &["/usr/local/share", "/usr/share"],
+    )
+    .iter()
+    .map(|path| FromPath::from_path(path))
+    .collect()
+}
+
+/// Searches for an existing configuration file of the provided application, first in the
+/// [config_home](fn.config_home.html) directory and then in the [config_dirs](fn.config_dirs.html)
+/// directories, and returns the first one found.
+///
+/// # Arguments
+///
+/// * `app` - The application name (subdirectory under the base directories)
+/// * `name` - The configuration file name (can include subdirectories)
+///
+/// # Example
+///
+/// ```
+/// use fsio::path::xdg;
+/// use std::path::PathBuf;
+///
+/// fn main() {
+///     let file: Option<PathBuf> = xdg::find_config_file("fsio_unknown_app", "config.toml");
+///
+///     assert!(file.is_none());
+/// }
+/// ```
+pub fn find_config_file<R: FromPath>(app: &str, name: &str) -> Option<R> {
+    let mut directories: Vec<PathBuf> = config_home().into_iter().collect();
+    directories.extend(config_dirs::<PathBuf>());
+
+    find_file(&directories, app, name).map(|path| FromPath::from_path(&path))
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+use crate::file::ensure_exists;
+
+#[test]
+fn resolve_home_absolute() {
+    let directory = resolve_home(Some(OsString::from("/custom/config")), ".config");
+
+    assert_eq!(directory.unwrap(), PathBuf::from("/custom/config"));
+}
+
+#[test]
+fn resolve_home_relative() {
+    let directory = resolve_home(Some(OsString::from("custom/config")), ".config");
+
+    assert_eq!(directory.unwrap(), get_home_directory().unwrap().join(".config"));
+}
+
+#[test]
+fn resolve_home_not_defined() {
+    let directory = resolve_home(None, ".local/share");
+
+    assert_eq!(directory.unwrap(), get_home_directory().unwrap().join(".local/share"));
+}
+
+#[test]
+fn resolve_directories_defined() {
+    let value = env::join_paths(&["/a", "relative", "/b"]).unwrap();
+    let directories = resolve_directories(Some(value), &["/etc/xdg"]);
+
+    assert_eq!(directories, vec![PathBuf::from("/a"), PathBuf::from("/b")]);
+}
+
+#[test]
+fn resolve_directories_not_defined() {
+    let directories = resolve_directories(None, &["/usr/local/share", "/usr/share"]);
+
+    assert_eq!(
+        directories,
+        vec![PathBuf::from("/usr/local/share"), PathBuf::from("/usr/share")]
+    );
+}
+
+#[test]
+fn resolve_directories_empty() {
+    let directories = resolve_directories(Some(OsString::new()), &["/etc/xdg"]);
+
+    assert_eq!(directories, vec![PathBuf::from("/etc/xdg")]);
+}
+
+#[test]
+fn find_file_search_order() {
+    let root = "./target/__test/ut/path_test/xdg/find_file_search_order";
+    ensure_exists(&format!("{}/dir2/app/config.toml", root)).unwrap();
+    ensure_exists(&format!("{}/dir3/app/config.toml", root)).unwrap();
+    crate::directory::create(&format!("{}/dir1/app/config.toml", root)).unwrap();
+
+    let directories = vec![
+        PathBuf::from(format!("{}/dir1", root)),
+        PathBuf::from(format!("{}/dir2", root)),
+        PathBuf::from(format!("{}/dir3", root)),
+    ];
+
+    let file = find_file(&directories, "app", "config.toml");
+    assert_eq!(
+        file.unwrap(),
+        PathBuf::from(format!("{}/dir2/app/config.toml", root))
+    );
+
+    let missing = find_file(&directories, "app", "missing.toml");
+    assert!(missing.is_none());
+}
+
+#[test]
+fn config_home_valid() {
+    let directory: Option<PathBuf> = config_home();
+
+    assert!(directory.unwrap().is_absolute());
+}
+
+#[test]
+fn config_dirs_valid() {
+    let directories: Vec<String> = config_dirs();
+
+    assert!(!directories.is_empty());
+}
+
+#[test]
+fn find_config_file_not_found() {
+    let file: Option<PathBuf> = find_config_file("fsio_unknown_app", "config.toml");
+
+    assert!(file.is_none());
+}
@@ -1,3 +1,4 @@
+use fsio::directory::CopyOptions;
 use fsio::{directory, file};
 use std::path::Path;
 
@@ -31,3 +32,71 @@ fn delete_test() {
 
     assert!(!path.exists());
 }
+
+#[test]
+fn copy_test() {
+    file::write_text_file("./target/__test/directory_test/copy/source/dir1/file.txt", "text")
+        .unwrap();
+
+    let result = directory::copy(
+        "./target/__test/directory_test/copy/source",
+        "./target/__test/directory_test/copy/target",
+        &CopyOptions::new(),
+    );
+    assert!(result.is_ok());
+
+    let text = file::read_text_file("./target/__test/directory_test/copy/target/dir1/file.txt")
+        .unwrap();
+    assert_eq!(text, "text");
+}
+
+#[test]
+fn walk_test() {
+    file::ensure_exists("./target/__test/directory_test/walk/dir1/file.txt").unwrap();
+
+    let count = directory::walk("./target/__test/directory_test/walk")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .count();
+
+    assert_eq!(count, 1);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_test() {
+    file::write_text_file("./target/__test/directory_test/hash/1/dir/file.txt", "content").unwrap();
+    file::write_text_file("./target/__test/directory_test/hash/2/dir/file.txt", "content").unwrap();
+
+    let first = directory::hash("./target/__test/directory_test/hash/1").unwrap();
+    let second = directory::hash("./target/__test/directory_test/hash/2").unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn manifest_test() {
+    file::write_text_file("./target/__test/directory_test/manifest/file.txt", "some content").unwrap();
+
+    let manifest = "./target/__test/directory_test/manifest/SHA256SUMS";
+    directory::write_manifest("./target/__test/directory_test/manifest", manifest).unwrap();
+
+    let report = directory::verify_manifest(manifest).unwrap();
+    assert!(report.is_valid());
+}
+
+#[test]
+fn move_dir_test() {
+    file::write_text_file("./target/__test/directory_test/move_dir_test/source/file.txt", "text")
+        .unwrap();
+
+    directory::move_dir(
+        "./target/__test/directory_test/move_dir_test/source",
+        "./target/__test/directory_test/move_dir_test/target",
+    )
+    .unwrap();
+
+    assert!(!Path::new("./target/__test/directory_test/move_dir_test/source").exists());
+    assert!(Path::new("./target/__test/directory_test/move_dir_test/target/file.txt").exists());
+}
@@ -3,6 +3,8 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 use std::str;
+use std::thread;
+use std::time::Duration;
 
 #[test]
 fn ensure_exists_test() {
@@ -71,6 +73,33 @@ fn append_and_read_file_test() {
     assert_eq!(str::from_utf8(&data).unwrap(), "some content\nmore content");
 }
 
+#[test]
+fn write_file_atomic_test() {
+    let file_path = "./target/__test/file_test/write_file_atomic/file.txt";
+    let mut result = file::write_file_atomic(file_path, "some content".as_bytes());
+    assert!(result.is_ok());
+    result = file::write_text_file_atomic(file_path, "more content");
+    assert!(result.is_ok());
+
+    let text = file::read_text_file(file_path).unwrap();
+
+    assert_eq!(text, "more content");
+}
+
+#[test]
+fn read_lines_test() {
+    let file_path = "./target/__test/file_test/read_lines/file.txt";
+    let result = file::write_text_file(file_path, "line 1\nline 2\r\nline 3");
+    assert!(result.is_ok());
+
+    let lines: Vec<String> = file::read_lines(file_path)
+        .unwrap()
+        .map(|line| line.unwrap())
+        .collect();
+
+    assert_eq!(lines, vec!["line 1", "line 2", "line 3"]);
+}
+
 #[test]
 fn delete_file_test() {
     let file_path = "./target/__test/file_test/delete_file/file.txt";
@@ -85,3 +114,128 @@ fn delete_file_test() {
 
     assert!(!path.exists());
 }
+
+#[test]
+#[cfg(feature = "hash")]
+fn hash_test() {
+    let file_path = "./target/__test/file_test/hash/file.txt";
+    file::write_text_file(file_path, "some content").unwrap();
+
+    let digest = file::hash(file_path, file::HashAlgorithm::Sha256).unwrap();
+
+    assert_eq!(
+        digest,
+        "290f493c44f5d63d06b374d0a5abd292fae38b92cab2fae5efefe1b0e9347f56"
+    );
+}
+
+#[test]
+fn write_file_if_changed_test() {
+    let file_path = "./target/__test/file_test/write_file_if_changed_test/file.txt";
+
+    let written = file::write_text_file_if_changed(file_path, "some content").unwrap();
+    assert!(written);
+
+    let written = file::write_text_file_if_changed(file_path, "some content").unwrap();
+    assert!(!written);
+}
+
+#[test]
+fn copy_test() {
+    let source = "./target/__test/file_test/copy_test/source.txt";
+    let target = "./target/__test/file_test/copy_test/dir/target.txt";
+    file::write_text_file(source, "some content").unwrap();
+
+    let size = file::copy(source, target, &file::CopyOptions::new()).unwrap();
+    assert_eq!(size, 12);
+
+    let text = file::read_text_file(target).unwrap();
+    assert_eq!(text, "some content");
+}
+
+#[test]
+fn move_file_test() {
+    let source = "./target/__test/file_test/move_file_test/source.txt";
+    let target = "./target/__test/file_test/move_file_test/dir/target.txt";
+    file::write_text_file(source, "some content").unwrap();
+
+    file::move_file(source, target).unwrap();
+
+    assert!(!Path::new(source).exists());
+    assert_eq!(file::read_text_file(target).unwrap(), "some content");
+}
+
+#[test]
+fn lock_test() {
+    let lock_path = "./target/__test/file_test/lock_test/state.lock";
+    let lock = file::lock_exclusive(lock_path).unwrap();
+
+    assert!(file::try_lock_shared(lock_path).unwrap().is_none());
+
+    drop(lock);
+    assert!(file::try_lock_shared(lock_path).unwrap().is_some());
+}
+
+#[test]
+fn lock_file_test() {
+    let lock_path = "./target/__test/file_test/lock_file_test/run.lock";
+    let lock = file::LockFile::acquire(lock_path).unwrap();
+
+    assert!(file::LockFile::acquire(lock_path).is_err());
+
+    drop(lock);
+    assert!(!Path::new(lock_path).exists());
+}
+
+#[test]
+#[cfg(feature = "mmap")]
+fn map_file_test() {
+    let file_path = "./target/__test/file_test/map_file_test/file.txt";
+    file::write_text_file(file_path, "some content").unwrap();
+
+    let content = unsafe { file::map_file(file_path) }.unwrap();
+
+    assert_eq!(&content[..], "some content".as_bytes());
+}
+
+#[test]
+fn ranged_io_test() {
+    let file_path = "./target/__test/file_test/ranged_io_test/file.txt";
+    file::write_text_file(file_path, "some content").unwrap();
+
+    file::write_at(file_path, 0, "SOME".as_bytes()).unwrap();
+
+    assert_eq!(file::read_range(file_path, 0, 4).unwrap(), "SOME".as_bytes());
+    assert_eq!(file::read_tail(file_path, 7).unwrap(), "content".as_bytes());
+}
+
+#[test]
+fn follow_test() {
+    let file_path = "./target/__test/file_test/follow_test/build.log";
+    file::write_text_file(file_path, "old line\n").unwrap();
+
+    let mut options = file::FollowOptions::new();
+    options.poll_interval = Duration::from_millis(10);
+    let lines = file::follow_with_options(file_path, &options);
+    let stop_handle = lines.stop_handle();
+
+    // stop waiting in case the line never arrives
+    let timeout_stop_handle = lines.stop_handle();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(10));
+        timeout_stop_handle.stop();
+    });
+
+    let writer = thread::spawn(move || {
+        file::append_text_file(file_path, "new line\n").unwrap();
+    });
+
+    let mut found = vec![];
+    for line in lines {
+        found.push(line.unwrap());
+        stop_handle.stop();
+    }
+    writer.join().unwrap();
+
+    assert_eq!(found, vec!["new line"]);
+}
@@ -98,3 +98,103 @@ fn get_last_modified_time_test() {
 
     assert!(time > 0);
 }
+
+#[test]
+fn matches_glob_test() {
+    assert!(path::matches_glob("src/**/*.rs", "./src/path/mod.rs"));
+}
+
+#[test]
+fn glob_test() {
+    let paths: Vec<PathBuf> = path::glob("src/**/*.rs").unwrap();
+
+    assert!(paths.contains(&PathBuf::from("src/path/mod.rs")));
+    assert!(paths.contains(&PathBuf::from("src/lib.rs")));
+}
+
+#[test]
+fn normalize_test() {
+    let normalized: PathBuf = path::normalize("./target/../src//path/./mod.rs");
+
+    assert_eq!(normalized, PathBuf::from("src/path/mod.rs"));
+}
+
+#[test]
+fn relative_to_test() {
+    let relative: PathBuf = path::relative_to("./src/path/mod.rs", "./tests").unwrap();
+
+    assert_eq!(relative, PathBuf::from("../src/path/mod.rs"));
+}
+
+#[test]
+fn canonical_relative_to_test() {
+    let relative: PathBuf = path::canonical_relative_to("./src/path/mod.rs", "./tests").unwrap();
+
+    assert_eq!(relative, PathBuf::from("../src/path/mod.rs"));
+}
+
+#[test]
+fn expand_test() {
+    std::env::set_var("FSIO_IT_EXPAND", "value");
+
+    let expanded: String = path::expand_strict("./${FSIO_IT_EXPAND}/${FSIO_IT_UNDEFINED:-out}")
+        .unwrap();
+
+    assert_eq!(expanded, "./value/out");
+}
+
+#[test]
+#[cfg(not(windows))]
+fn expand_home_not_expanded_test() {
+    let home = std::env::var_os("HOME");
+    std::env::set_var("HOME", "/home/a$FSIO_IT_HOME_UNDEFINED");
+
+    let expanded: String = path::expand("~/$FSIO_IT_HOME_UNDEFINED/f");
+    let strict_expanded: Result<String, _> = path::expand_strict("~/f");
+
+    match home {
+        Some(value) => std::env::set_var("HOME", value),
+        None => std::env::remove_var("HOME"),
+    }
+    assert_eq!(expanded, "/home/a$FSIO_IT_HOME_UNDEFINED//f");
+    assert_eq!(strict_expanded.unwrap(), "/home/a$FSIO_IT_HOME_UNDEFINED/f");
+}
+
+#[test]
+fn join_within_test() {
+    let joined: PathBuf = path::join_within("./target", "a/../b").unwrap();
+    assert_eq!(joined, PathBuf::from("./target/b"));
+
+    let result: Result<PathBuf, _> = path::join_within("./target", "../b");
+    assert!(result.is_err());
+}
+
+#[test]
+fn which_test() {
+    let cargo: Option<PathBuf> = path::which("cargo");
+    assert!(cargo.is_some());
+
+    let all: Vec<PathBuf> = path::which_all("cargo");
+    assert_eq!(all.first(), cargo.as_ref());
+}
+
+#[test]
+fn xdg_test() {
+    let config_home: PathBuf = path::xdg::config_home().unwrap();
+    assert!(config_home.is_absolute());
+
+    let data_home: PathBuf = path::xdg::data_home().unwrap();
+    assert!(data_home.is_absolute());
+
+    let cache_home: PathBuf = path::xdg::cache_home().unwrap();
+    assert!(cache_home.is_absolute());
+
+    let state_home: PathBuf = path::xdg::state_home().unwrap();
+    assert!(state_home.is_absolute());
+
+    let config_dirs: Vec<PathBuf> = path::xdg::config_dirs();
+    assert!(!config_dirs.is_empty());
+
+    let data_dirs: Vec<PathBuf> = path::xdg::data_dirs();
+    assert!(!data_dirs.is_empty());
+}